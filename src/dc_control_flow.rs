@@ -0,0 +1,165 @@
+//! Implement extension methods over `ControlFlow<B, C>`.
+
+use std::ops::ControlFlow;
+
+#[cfg(debug_assertions)]
+use std::fmt::Debug;
+
+#[cfg(debug_assertions)]
+use crate::dc_result::unwrap_failed;
+
+/// Defines methods to extract the `Continue(C)` of a `ControlFlow<B, C>` without performing checks on Release, but panicking on Debug.
+///
+/// On Debug it's only implemented when `B: Debug`, as it's required to report the unexpected `Break(B)`.
+/// On Release there is no such requirement.
+pub trait DcControlFlowContinue {
+    /// Break type in `ControlFlow<B, C>`.
+    type B;
+
+    /// Continue type in `ControlFlow<B, C>`.
+    type C;
+
+    /// Unwraps the continue content of the control flow without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Break(B)`, showing its content.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Continue(C)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_continue_dc(self) -> Self::C;
+
+    /// Unwraps the continue content of the control flow without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Break(B)`, showing its content.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Continue(C)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_continue_dc(self, msg: &str) -> Self::C;
+}
+
+/// Defines methods to extract the `Break(B)` of a `ControlFlow<B, C>` without performing checks on Release, but panicking on Debug.
+///
+/// On Debug it's only implemented when `C: Debug`, as it's required to report the unexpected `Continue(C)`.
+/// On Release there is no such requirement.
+pub trait DcControlFlowBreak {
+    /// Break type in `ControlFlow<B, C>`.
+    type B;
+
+    /// Continue type in `ControlFlow<B, C>`.
+    type C;
+
+    /// Unwraps the break content of the control flow without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Continue(C)`, showing its content.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Break(B)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_break_dc(self) -> Self::B;
+
+    /// Unwraps the break content of the control flow without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Continue(C)`, showing its content.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Break(B)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_break_dc(self, msg: &str) -> Self::B;
+}
+
+#[cfg(debug_assertions)]
+impl<B: Debug, C> DcControlFlowContinue for ControlFlow<B, C> {
+    type B = B;
+    type C = C;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_continue_dc(self) -> Self::C {
+        match self {
+            ControlFlow::Continue(value) => value,
+            ControlFlow::Break(value) => unwrap_failed("called `unwrap_continue_dc()` on a `Break` value", &value),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_continue_dc(self, msg: &str) -> Self::C {
+        match self {
+            ControlFlow::Continue(value) => value,
+            ControlFlow::Break(value) => unwrap_failed(msg, &value),
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<B, C: Debug> DcControlFlowBreak for ControlFlow<B, C> {
+    type B = B;
+    type C = C;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_break_dc(self) -> Self::B {
+        match self {
+            ControlFlow::Break(value) => value,
+            ControlFlow::Continue(value) => unwrap_failed("called `unwrap_break_dc()` on a `Continue` value", &value),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_break_dc(self, msg: &str) -> Self::B {
+        match self {
+            ControlFlow::Break(value) => value,
+            ControlFlow::Continue(value) => unwrap_failed(msg, &value),
+        }
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<B, C> DcControlFlowContinue for ControlFlow<B, C> {
+    type B = B;
+    type C = C;
+
+    #[inline(always)]
+    unsafe fn unwrap_continue_dc(self) -> Self::C {
+        self.continue_value().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_continue_dc(self, _msg: &str) -> Self::C {
+        self.continue_value().unwrap_unchecked()
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<B, C> DcControlFlowBreak for ControlFlow<B, C> {
+    type B = B;
+    type C = C;
+
+    #[inline(always)]
+    unsafe fn unwrap_break_dc(self) -> Self::B {
+        self.break_value().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_break_dc(self, _msg: &str) -> Self::B {
+        self.break_value().unwrap_unchecked()
+    }
+}
@@ -0,0 +1,121 @@
+//! Implement a cursor over `[U]` that doesn't perform bounds checks on Release.
+
+/// A streaming reader over a slice, which does not perform checks in release, but it does in debug.
+///
+/// It's created with `DcSliceElements::cursor_dc` and keeps track of the current position in the slice.
+#[derive(Debug, Clone, Copy)]
+pub struct DcCursor<'a, U> {
+    slice: &'a [U],
+    position: usize,
+}
+
+impl<'a, U> DcCursor<'a, U> {
+    /// Creates a new cursor at the start of `slice`.
+    #[inline(always)]
+    pub const fn new(slice: &'a [U]) -> Self {
+        Self { slice, position: 0 }
+    }
+
+    /// Returns the current position of the cursor.
+    #[inline(always)]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of elements that haven't been read yet.
+    #[inline(always)]
+    pub const fn remaining_len(&self) -> usize {
+        self.slice.len() - self.position
+    }
+
+    /// Returns the elements that haven't been read yet, without advancing the cursor.
+    #[inline(always)]
+    pub fn remaining(&self) -> &'a [U] {
+        // SAFETY: `position` is never greater than the length of the slice, as long as the safety contracts of `advance_dc` and `rewind_dc` were upheld.
+        unsafe { self.slice.get_unchecked(self.position..) }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<'a, U> DcCursor<'a, U> {
+    /// Returns the next `n` elements and advances the cursor past them, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if there are fewer than `n` elements remaining.
+    ///
+    /// # Safety
+    ///
+    /// There must be at least `n` elements remaining.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn advance_dc(&mut self, n: usize) -> &'a [U] {
+        assert!(
+            n <= self.remaining_len(),
+            "cannot advance {} elements from position {} in a slice of length {}",
+            n,
+            self.position,
+            self.slice.len()
+        );
+        let start = self.position;
+        self.position += n;
+        &self.slice[start..self.position]
+    }
+
+    /// Moves the cursor `n` elements back, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `n` is greater than the current position.
+    ///
+    /// # Safety
+    ///
+    /// `n` must not be greater than the current position.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn rewind_dc(&mut self, n: usize) {
+        assert!(n <= self.position, "cannot rewind {} elements from position {}", n, self.position);
+        self.position -= n;
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<'a, U> DcCursor<'a, U> {
+    /// Returns the next `n` elements and advances the cursor past them, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if there are fewer than `n` elements remaining.
+    ///
+    /// # Safety
+    ///
+    /// There must be at least `n` elements remaining.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    pub unsafe fn advance_dc(&mut self, n: usize) -> &'a [U] {
+        let start = self.position;
+        self.position = start.unchecked_add(n);
+        self.slice.get_unchecked(start..self.position)
+    }
+
+    /// Moves the cursor `n` elements back, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `n` is greater than the current position.
+    ///
+    /// # Safety
+    ///
+    /// `n` must not be greater than the current position.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    pub unsafe fn rewind_dc(&mut self, n: usize) {
+        self.position = self.position.unchecked_sub(n);
+    }
+}
@@ -1,5 +1,15 @@
 //! Implement extension methods over `Option<T>`.
 
+use std::{fmt, ops::{Deref, DerefMut}};
+
+#[cfg(all(debug_assertions, feature = "backtrace"))]
+use std::backtrace::Backtrace;
+
+#[cfg(all(not(debug_assertions), feature = "soft_release"))]
+use std::panic::Location;
+
+use crate::unreachable_dc;
+
 /// Defines methods to extract the `Some(T)` of an `Option<T>` without performing checks on Release, but panicking on Debug.
 pub trait DcOption {
     /// Type in `Option<T>`.
@@ -7,6 +17,9 @@ pub trait DcOption {
 
     /// Unwraps the content of the option without performing checks on release.
     ///
+    /// On Release, options of niche-optimized types such as `Option<bool>`, `Option<char>` or `Option<NonZeroU8>`
+    /// are unwrapped by moving the value, without any comparison nor branch.
+    ///
     /// # Panics (Debug)
     ///
     /// Panics if `self` is `None`.
@@ -30,25 +43,408 @@ pub trait DcOption {
     ///
     ///  Failing this produces undefined behavior on Release.
     unsafe fn expect_dc(self, msg: &str) -> Self::Result;
+
+    /// Unwraps the content of the option without performing checks on release and maps it with `f`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn map_dc<U, F: FnOnce(Self::Result) -> U>(self, f: F) -> U;
+
+    /// Unwraps the content of the option without performing checks on release and maps it with `f`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn map_expect_dc<U, F: FnOnce(Self::Result) -> U>(self, f: F, msg: &str) -> U;
+
+    /// Returns a reference to the content of the option without performing checks on release.
+    ///
+    /// As it takes `self` by reference, it can be called through `Deref` (and `DerefMut`) wrappers such as `Box`, `Rc` or `RefMut`.
+    /// The returned reference borrows from `self`, so calls can be chained directly (e.g. `cell.borrow().unwrap_dc_ref().len()`),
+    /// although a temporary guard such as `Ref` must be bound to a variable if the reference must outlive the statement.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enderlook_debug_checked::{DcOption, DcRefCell};
+    ///
+    /// let name = Some(String::from("name"));
+    /// assert_eq!(unsafe { name.unwrap_dc_ref().len() }, 4);
+    ///
+    /// // Through the `Ref<'_, Option<String>>` returned by `borrow`.
+    /// let cell = DcRefCell::new(Some(String::from("name")));
+    /// assert_eq!(unsafe { cell.borrow().unwrap_dc_ref().len() }, 4);
+    ///
+    /// // The guard must be bound to a variable for the reference to outlive the statement.
+    /// let guard = unsafe { cell.borrow() };
+    /// let name: &String = unsafe { guard.unwrap_dc_ref() };
+    /// assert_eq!(name, "name");
+    /// ```
+    unsafe fn unwrap_dc_ref(&self) -> &Self::Result;
+
+    /// Returns a reference to the content of the option without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn expect_dc_ref(&self, msg: &str) -> &Self::Result;
+
+    /// Returns a mutable reference to the content of the option without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enderlook_debug_checked::{DcOption, DcRefCell};
+    ///
+    /// let cell = DcRefCell::new(Some(vec![1]));
+    /// unsafe { cell.borrow_mut().unwrap_dc_mut().push(2) };
+    /// assert_eq!(unsafe { cell.borrow().unwrap_dc_ref().len() }, 2);
+    /// ```
+    unsafe fn unwrap_dc_mut(&mut self) -> &mut Self::Result;
+
+    /// Returns a mutable reference to the content of the option without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn expect_dc_mut(&mut self, msg: &str) -> &mut Self::Result;
+
+    /// Unwraps the content of the option without performing checks on release.
+    ///
+    /// Unlike `expect_dc`, the message is taken as `fmt::Arguments` (built with `format_args!`), so it's only formatted if the panic happens.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified formatted message if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn expect_dc_fmt(self, args: fmt::Arguments) -> Self::Result;
+
+    /// Unwraps the content of the option without performing checks on release, alongside a `SomeWitness` proving the extraction happened.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_dc_witness(self) -> (Self::Result, SomeWitness);
+
+    /// Returns the content of whichever of `self` and `other` is `Some(T)` without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if both `self` and `other` are `Some(T)`, or if both are `None`.
+    ///
+    /// # Safety
+    ///
+    /// Exactly one of `self` and `other` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn xor_unwrap_dc(self, other: Option<Self::Result>) -> Self::Result;
+
+    /// Unwraps the content of the option, treating `None` as logically impossible, without performing checks on release.
+    ///
+    /// This behaves like `unwrap_dc`, but it routes the `None` case through `unreachable_dc`,
+    /// making explicit at the call site that `None` can't happen rather than merely not being checked.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with `unreachable_dc` if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_or_unreachable_dc(self) -> Self::Result;
+
+    /// Inserts the value computed from `f` into the option, which is asserted to be `None`, and returns a mutable reference to it.
+    ///
+    /// Unlike `get_or_insert_with`, this is meant for the call that initializes the option, so `f` is always run.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is already `Some(T)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `None`.
+    ///
+    ///  Failing this replaces (and drops) the previous value on Release.
+    unsafe fn get_or_insert_with_once_dc<F: FnOnce() -> Self::Result>(&mut self, f: F) -> &mut Self::Result;
+
+    /// Unwraps the content of the option without performing checks on release, with a lazily computed context for the panic message.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the `Display` output of the value returned by `f` if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn with_context_dc<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Self::Result;
+
+    /// Transforms the option into a `Result`, mapping `None` to `Err(err())`.
+    ///
+    /// This is meant for options which are expected to never be `None`, but whose failure should be recoverable with `?` instead of panicking,
+    /// so tests can assert on the error.
+    /// As `None` is still handled on Release, this is safe; the only difference is that on Release the `None` path is marked as cold.
+    fn ok_or_dc<E, F: FnOnce() -> E>(self, err: F) -> Result<Self::Result, E>;
+
+    /// Returns a reference to the dereferenced content of the option without performing checks on release.
+    ///
+    /// This is the same as `as_deref` followed by `unwrap_dc`, so `Option<String>` produces a `&str` and `Option<Vec<T>>` produces a `&[T]`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn as_deref_dc(&self) -> &<Self::Result as Deref>::Target
+    where
+        Self::Result: Deref;
+
+    /// Returns a mutable reference to the dereferenced content of the option without performing checks on release.
+    ///
+    /// This is the same as `as_deref_mut` followed by `unwrap_dc`, so `Option<String>` produces a `&mut str` and `Option<Vec<T>>` produces a `&mut [T]`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn as_deref_dc_mut(&mut self) -> &mut <Self::Result as Deref>::Target
+    where
+        Self::Result: DerefMut;
+
+    /// Unwraps the content of the option without performing checks on release, with a fallback for the `soft_release` feature.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    ///  Failing this produces undefined behavior on Release, unless the `soft_release` feature is enabled,
+    /// in which case a warning with the caller location is printed to the standard error and `default` is returned.
+    unsafe fn unwrap_dc_or(self, default: Self::Result) -> Self::Result;
 }
 
 #[cfg(debug_assertions)]
 impl<T> DcOption for Option<T> {
     type Result = T;
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn unwrap_dc(self) -> Self::Result {
-        self.unwrap()
+        match self {
+            Some(value) => value,
+            None => unwrap_failed("called `Option::unwrap()` on a `None` value"),
+        }
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn expect_dc(self, msg: &str) -> Self::Result {
-        self.expect(msg)
+        match self {
+            Some(value) => value,
+            None => unwrap_failed(msg),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn map_dc<U, F: FnOnce(Self::Result) -> U>(self, f: F) -> U {
+        f(self.unwrap())
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn map_expect_dc<U, F: FnOnce(Self::Result) -> U>(self, f: F, msg: &str) -> U {
+        f(self.expect(msg))
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_ref(&self) -> &Self::Result {
+        self.as_ref().unwrap()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_dc_ref(&self, msg: &str) -> &Self::Result {
+        self.as_ref().expect(msg)
     }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_mut(&mut self) -> &mut Self::Result {
+        self.as_mut().unwrap()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_dc_mut(&mut self, msg: &str) -> &mut Self::Result {
+        self.as_mut().expect(msg)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_dc_fmt(self, args: fmt::Arguments) -> Self::Result {
+        match self {
+            Some(value) => value,
+            None => panic!("{}", args),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_witness(self) -> (Self::Result, SomeWitness) {
+        (self.unwrap(), SomeWitness(()))
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn xor_unwrap_dc(self, other: Option<T>) -> Self::Result {
+        match (self, other) {
+            (Some(value), None) | (None, Some(value)) => value,
+            (Some(_), Some(_)) => panic!("expected exactly one `Some` value, but both options are `Some`"),
+            (None, None) => panic!("expected exactly one `Some` value, but both options are `None`"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_or_unreachable_dc(self) -> Self::Result {
+        match self {
+            Some(value) => value,
+            None => unreachable_dc(),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_or_insert_with_once_dc<F: FnOnce() -> Self::Result>(&mut self, f: F) -> &mut Self::Result {
+        assert!(self.is_none(), "option is already initialized");
+        self.insert(f())
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn with_context_dc<C: fmt::Display, F: FnOnce() -> C>(self, f: F) -> Self::Result {
+        match self {
+            Some(value) => value,
+            None => panic!("{}", f()),
+        }
+    }
+
+    #[inline]
+    fn ok_or_dc<E, F: FnOnce() -> E>(self, err: F) -> Result<Self::Result, E> {
+        self.ok_or_else(err)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn as_deref_dc(&self) -> &<Self::Result as Deref>::Target
+    where
+        Self::Result: Deref,
+    {
+        self.as_deref().unwrap()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn as_deref_dc_mut(&mut self) -> &mut <Self::Result as Deref>::Target
+    where
+        Self::Result: DerefMut,
+    {
+        self.as_deref_mut().unwrap()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_or(self, _default: Self::Result) -> Self::Result {
+        self.unwrap_dc()
+    }
+}
+
+/// Panics with `msg`, followed by a backtrace if the `backtrace` feature is enabled.
+#[cfg(debug_assertions)]
+#[cold]
+#[track_caller]
+fn unwrap_failed(msg: &str) -> ! {
+    #[cfg(feature = "backtrace")]
+    panic!("{}\n\nbacktrace:\n{}", msg, Backtrace::force_capture());
+    #[cfg(not(feature = "backtrace"))]
+    panic!("{}", msg);
 }
 
+// `unwrap_unchecked` compiles to the same code as matching and calling `unreachable_dc` on `None`, so it's used directly.
 #[cfg(not(debug_assertions))]
 impl<T> DcOption for Option<T> {
     type Result = T;
@@ -62,4 +458,236 @@ impl<T> DcOption for Option<T> {
     unsafe fn expect_dc(self, _msg: &str) -> Self::Result {
         self.unwrap_unchecked()
     }
+
+    #[inline(always)]
+    unsafe fn map_dc<U, F: FnOnce(Self::Result) -> U>(self, f: F) -> U {
+        f(self.unwrap_unchecked())
+    }
+
+    #[inline(always)]
+    unsafe fn map_expect_dc<U, F: FnOnce(Self::Result) -> U>(self, f: F, _msg: &str) -> U {
+        f(self.unwrap_unchecked())
+    }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_ref(&self) -> &Self::Result {
+        self.as_ref().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_dc_ref(&self, _msg: &str) -> &Self::Result {
+        self.as_ref().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_mut(&mut self) -> &mut Self::Result {
+        self.as_mut().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_dc_mut(&mut self, _msg: &str) -> &mut Self::Result {
+        self.as_mut().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_dc_fmt(self, _args: fmt::Arguments) -> Self::Result {
+        self.unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_witness(self) -> (Self::Result, SomeWitness) {
+        (self.unwrap_unchecked(), SomeWitness(()))
+    }
+
+    #[inline(always)]
+    unsafe fn xor_unwrap_dc(self, other: Option<T>) -> Self::Result {
+        match self {
+            Some(value) => value,
+            None => other.unwrap_unchecked(),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn unwrap_or_unreachable_dc(self) -> Self::Result {
+        match self {
+            Some(value) => value,
+            None => unreachable_dc(),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn get_or_insert_with_once_dc<F: FnOnce() -> Self::Result>(&mut self, f: F) -> &mut Self::Result {
+        self.insert(f())
+    }
+
+    #[inline(always)]
+    unsafe fn with_context_dc<C: fmt::Display, F: FnOnce() -> C>(self, _f: F) -> Self::Result {
+        self.unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    fn ok_or_dc<E, F: FnOnce() -> E>(self, err: F) -> Result<Self::Result, E> {
+        #[cold]
+        #[inline(never)]
+        fn cold_err<E, F: FnOnce() -> E>(err: F) -> E {
+            err()
+        }
+
+        match self {
+            Some(value) => Ok(value),
+            None => Err(cold_err(err)),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn as_deref_dc(&self) -> &<Self::Result as Deref>::Target
+    where
+        Self::Result: Deref,
+    {
+        self.as_deref().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn as_deref_dc_mut(&mut self) -> &mut <Self::Result as Deref>::Target
+    where
+        Self::Result: DerefMut,
+    {
+        self.as_deref_mut().unwrap_unchecked()
+    }
+
+    #[cfg(not(feature = "soft_release"))]
+    #[inline(always)]
+    unsafe fn unwrap_dc_or(self, _default: Self::Result) -> Self::Result {
+        self.unwrap_unchecked()
+    }
+
+    #[cfg(feature = "soft_release")]
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_or(self, default: Self::Result) -> Self::Result {
+        match self {
+            Some(value) => value,
+            None => {
+                eprintln!("warning: called `unwrap_dc_or` on a `None` value at {}, returning the fallback", Location::caller());
+                default
+            }
+        }
+    }
+}
+
+/// Unwraps the content of `option` without performing checks on release.
+///
+/// This is the free function form of `DcOption::unwrap_dc`, which can be referenced as a function item.
+///
+/// # Panics (Debug)
+///
+/// Panics if `option` is `None`.
+///
+/// # Safety
+///
+/// `option` must be `Some(T)`.
+///
+///  Failing this produces undefined behavior on Release.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+pub unsafe fn unwrap_dc<T>(option: Option<T>) -> T {
+    option.unwrap_dc()
 }
+
+/// Unwraps the content of `option` without performing checks on release.
+///
+/// This is the free function form of `DcOption::unwrap_dc`, which can be referenced as a function item.
+///
+/// # Panics (Debug)
+///
+/// Panics if `option` is `None`.
+///
+/// # Safety
+///
+/// `option` must be `Some(T)`.
+///
+///  Failing this produces undefined behavior on Release.
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub unsafe fn unwrap_dc<T>(option: Option<T>) -> T {
+    option.unwrap_dc()
+}
+
+/// Defines methods to extract the `Some(Some(T))` of an `Option<Option<T>>` without performing checks on Release, but panicking on Debug.
+pub trait DcOptionFlatten {
+    /// Type in `Option<Option<T>>`.
+    type Result;
+
+    /// Unwraps both layers of the option without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None` or `Some(None)`, stating which layer is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(Some(T))`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_dc_flatten(self) -> Self::Result;
+
+    /// Unwraps both layers of the option without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `None` or `Some(None)`, followed by which layer is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(Some(T))`.
+    ///
+    ///  Failing this produces undefined behavior on Release.
+    unsafe fn expect_dc_flatten(self, msg: &str) -> Self::Result;
+}
+
+#[cfg(debug_assertions)]
+impl<T> DcOptionFlatten for Option<Option<T>> {
+    type Result = T;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_flatten(self) -> Self::Result {
+        match self {
+            Some(Some(value)) => value,
+            Some(None) => panic!("called `unwrap_dc_flatten` on a `Some(None)` value (inner layer is `None`)"),
+            None => panic!("called `unwrap_dc_flatten` on a `None` value (outer layer is `None`)"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_dc_flatten(self, msg: &str) -> Self::Result {
+        match self {
+            Some(Some(value)) => value,
+            Some(None) => panic!("{} (inner layer is `None`)", msg),
+            None => panic!("{} (outer layer is `None`)", msg),
+        }
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<T> DcOptionFlatten for Option<Option<T>> {
+    type Result = T;
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_flatten(self) -> Self::Result {
+        self.unwrap_unchecked().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_dc_flatten(self, _msg: &str) -> Self::Result {
+        self.unwrap_unchecked().unwrap_unchecked()
+    }
+}
+
+/// Zero-sized proof token returned by `DcOption::unwrap_dc_witness`.
+///
+/// It can only be obtained from an extraction, so APIs can require it as evidence that an `Option<T>` was `Some(T)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SomeWitness(());
@@ -0,0 +1,82 @@
+//! Implement extension methods over `Poll<T>`.
+
+use std::task::Poll;
+
+#[cfg(not(debug_assertions))]
+use std::hint;
+
+/// Defines methods to extract the `Ready(T)` of a `Poll<T>` without performing checks on Release, but panicking on Debug.
+pub trait DcPoll {
+    /// Type in `Poll<T>`.
+    type Result;
+
+    /// Unwraps the ready content of the poll without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Pending`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ready(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_ready_dc(self) -> Self::Result;
+
+    /// Unwraps the ready content of the poll without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Pending`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ready(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_ready_dc(self, msg: &str) -> Self::Result;
+}
+
+#[cfg(debug_assertions)]
+impl<T> DcPoll for Poll<T> {
+    type Result = T;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_ready_dc(self) -> Self::Result {
+        match self {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("called `unwrap_ready_dc()` on a `Pending` value"),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_ready_dc(self, msg: &str) -> Self::Result {
+        match self {
+            Poll::Ready(value) => value,
+            Poll::Pending => panic!("{}", msg),
+        }
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<T> DcPoll for Poll<T> {
+    type Result = T;
+
+    #[inline(always)]
+    unsafe fn unwrap_ready_dc(self) -> Self::Result {
+        match self {
+            Poll::Ready(value) => value,
+            Poll::Pending => hint::unreachable_unchecked(),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn expect_ready_dc(self, _msg: &str) -> Self::Result {
+        match self {
+            Poll::Ready(value) => value,
+            Poll::Pending => hint::unreachable_unchecked(),
+        }
+    }
+}
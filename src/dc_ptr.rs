@@ -0,0 +1,119 @@
+//! Implement functions over raw pointers.
+
+use std::ptr;
+
+#[cfg(not(debug_assertions))]
+use std::hint;
+
+/// Informs the compiler that `ptr` is aligned to `align` bytes.
+///
+/// # Panics (Debug)
+///
+/// Panics if `align` is not a power of two or `ptr` is not aligned to `align` bytes.
+///
+/// # Safety
+///
+/// `align` must be a power of two and `ptr` must be aligned to `align` bytes.
+///
+/// Failing this produces undefined behavior on Release.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+pub unsafe fn assume_aligned_dc<T>(ptr: *const T, align: usize) -> *const T {
+    assert!(align.is_power_of_two(), "alignment {} is not a power of two", align);
+    assert!(ptr.addr().is_multiple_of(align), "pointer {:p} is not aligned to {} bytes", ptr, align);
+    ptr
+}
+
+/// Informs the compiler that `ptr` is aligned to `align` bytes.
+///
+/// # Panics (Debug)
+///
+/// Panics if `align` is not a power of two or `ptr` is not aligned to `align` bytes.
+///
+/// # Safety
+///
+/// `align` must be a power of two and `ptr` must be aligned to `align` bytes.
+///
+/// Failing this produces undefined behavior on Release.
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub unsafe fn assume_aligned_dc<T>(ptr: *const T, align: usize) -> *const T {
+    hint::assert_unchecked(ptr.addr() & (align - 1) == 0);
+    ptr
+}
+
+/// Informs the compiler that `ptr` is aligned to `align` bytes.
+///
+/// # Panics (Debug)
+///
+/// Panics if `align` is not a power of two or `ptr` is not aligned to `align` bytes.
+///
+/// # Safety
+///
+/// `align` must be a power of two and `ptr` must be aligned to `align` bytes.
+///
+/// Failing this produces undefined behavior on Release.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+pub unsafe fn assume_aligned_dc_mut<T>(ptr: *mut T, align: usize) -> *mut T {
+    assert!(align.is_power_of_two(), "alignment {} is not a power of two", align);
+    assert!(ptr.addr().is_multiple_of(align), "pointer {:p} is not aligned to {} bytes", ptr, align);
+    ptr
+}
+
+/// Informs the compiler that `ptr` is aligned to `align` bytes.
+///
+/// # Panics (Debug)
+///
+/// Panics if `align` is not a power of two or `ptr` is not aligned to `align` bytes.
+///
+/// # Safety
+///
+/// `align` must be a power of two and `ptr` must be aligned to `align` bytes.
+///
+/// Failing this produces undefined behavior on Release.
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub unsafe fn assume_aligned_dc_mut<T>(ptr: *mut T, align: usize) -> *mut T {
+    hint::assert_unchecked(ptr.addr() & (align - 1) == 0);
+    ptr
+}
+
+/// Reconstructs a boxed slice from a pointer to its first element and its length.
+///
+/// # Panics (Debug)
+///
+/// Panics if `ptr` is null or not aligned for `T`.
+///
+/// # Safety
+///
+/// `ptr` must be non-null and aligned for `T` (even if `len` is zero), and it must come from a `Box<[T]>` of length `len` (e.g. through `Box::into_raw`) that hasn't been freed.
+///
+/// Failing this produces undefined behavior on Release.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+pub unsafe fn box_slice_from_raw_dc<T>(ptr: *mut T, len: usize) -> Box<[T]> {
+    assert!(!ptr.is_null(), "pointer to a boxed slice of length {} is null", len);
+    assert!(ptr.is_aligned(), "pointer {:p} is not aligned to {} bytes", ptr, align_of::<T>());
+    Box::from_raw(ptr::slice_from_raw_parts_mut(ptr, len))
+}
+
+/// Reconstructs a boxed slice from a pointer to its first element and its length.
+///
+/// # Panics (Debug)
+///
+/// Panics if `ptr` is null or not aligned for `T`.
+///
+/// # Safety
+///
+/// `ptr` must be non-null and aligned for `T` (even if `len` is zero), and it must come from a `Box<[T]>` of length `len` (e.g. through `Box::into_raw`) that hasn't been freed.
+///
+/// Failing this produces undefined behavior on Release.
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub unsafe fn box_slice_from_raw_dc<T>(ptr: *mut T, len: usize) -> Box<[T]> {
+    Box::from_raw(ptr::slice_from_raw_parts_mut(ptr, len))
+}
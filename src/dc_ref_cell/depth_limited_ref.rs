@@ -0,0 +1,87 @@
+use std::{ops::Deref, fmt::{Display, Formatter, Error}};
+
+#[cfg(debug_assertions)]
+use std::{cell::RefCell, collections::HashMap};
+
+#[cfg(debug_assertions)]
+thread_local! {
+    // Number of live `DepthLimitedRef`s per cell, keyed by the address of the cell.
+    // `DcRefCell` is never `Sync`, so all the guards of a cell live in the same thread.
+    static DEPTHS: RefCell<HashMap<usize, usize>> = RefCell::new(HashMap::new());
+}
+
+/// A wrapper type for an immutably borrowed value from a `DcRefCell<T>` obtained with `borrow_depth_limited_dc`.
+///
+/// On Debug, each guard counts towards the borrow depth of its cell until it's dropped.
+#[cfg(debug_assertions)]
+#[must_not_suspend = "holding a Ref across suspend points can cause BorrowErrors"]
+#[derive(Debug)]
+pub struct DepthLimitedRef<'b, T: ?Sized + 'b> {
+    borrow: std::cell::Ref<'b, T>,
+    cell: usize,
+}
+
+/// A wrapper type for an immutably borrowed value from a `DcRefCell<T>` obtained with `borrow_depth_limited_dc`.
+///
+/// On Debug, each guard counts towards the borrow depth of its cell until it's dropped.
+#[cfg(not(debug_assertions))]
+#[must_not_suspend = "holding a Ref across suspend points can cause BorrowErrors"]
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct DepthLimitedRef<'b, T: ?Sized + 'b>(pub(super) &'b T);
+
+#[cfg(debug_assertions)]
+impl<'b, T: ?Sized> DepthLimitedRef<'b, T> {
+    /// Wraps `borrow`, increasing the borrow depth of `cell`.
+    #[inline]
+    #[track_caller]
+    pub(super) fn new(borrow: std::cell::Ref<'b, T>, cell: usize, max_depth: usize) -> Self {
+        let depth = DEPTHS.with(|depths| depths.borrow().get(&cell).copied().unwrap_or(0)) + 1;
+        assert!(depth <= max_depth, "borrow depth {} exceeds the limit of {}", depth, max_depth);
+        DEPTHS.with(|depths| depths.borrow_mut().insert(cell, depth));
+        Self { borrow, cell }
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<T: ?Sized> Drop for DepthLimitedRef<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        DEPTHS.with(|depths| {
+            let mut depths = depths.borrow_mut();
+            match depths.get_mut(&self.cell) {
+                Some(1) | None => {
+                    depths.remove(&self.cell);
+                }
+                Some(depth) => *depth -= 1,
+            }
+        });
+    }
+}
+
+impl<T: ?Sized> Deref for DepthLimitedRef<'_, T> {
+    /// The resulting type after dereferencing.
+    type Target = T;
+
+    /// Dereferences the value.
+    #[cfg(debug_assertions)]
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.borrow
+    }
+
+    /// Dereferences the value.
+    #[cfg(not(debug_assertions))]
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl<T: Display + ?Sized> Display for DepthLimitedRef<'_, T> {
+    /// Formats the value using the given formatter.
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        Display::fmt(&**self, f)
+    }
+}
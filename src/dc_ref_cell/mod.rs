@@ -1,8 +1,21 @@
+mod depth_limited_ref;
 mod r#ref;
 mod ref_mut;
 
-use std::{cell::{RefCell, UnsafeCell}, mem, fmt::{Debug, Formatter, Result}, cmp::Ordering, ops::CoerceUnsized, marker::PhantomData, ptr::NonNull};
+use std::{mem, ptr, cell::{RefCell, UnsafeCell}, fmt::Debug, cmp::Ordering, ops::CoerceUnsized, pin::Pin};
 
+use crate::replace_with_dc;
+
+#[cfg(debug_assertions)]
+use std::{alloc::{self, Layout}, panic::{self, Location}, process};
+
+#[cfg(not(debug_assertions))]
+use std::{fmt::{Formatter, Result}, marker::PhantomData, ptr::NonNull};
+
+#[cfg(all(not(debug_assertions), feature = "debug_contents"))]
+use std::any;
+
+pub use depth_limited_ref::DepthLimitedRef;
 pub use r#ref::Ref;
 pub use ref_mut::RefMut;
 
@@ -14,7 +27,10 @@ pub use ref_mut::RefMut;
 ///
 /// To simplify debugging and finding errors, all the operations whose checks only run at debug are marked as `unsafe`.
 ///
-/// For this reason the type doesn't implement `Clone`, `Eq`, `Ord`, `PartialEq` nor `PartialOrd` unlike `RefCell<T>`, as they would be unsafe but we can't mark them as such.
+/// For this reason the type doesn't implement `Clone`, `Eq`, `Ord`, `PartialEq`, `PartialOrd` nor `Serialize` unlike `RefCell<T>`, as they would be unsafe but we can't mark them as such.
+/// With the `serde` feature, the `Ref` and `RefMut` guards implement `Serialize` instead, so the value is serialized through the guard returned by `borrow`.
+///
+/// On both Debug and Release, it's `Send` when `T: Send` and never `Sync`, just like `RefCell<T>`.
 #[cfg(debug_assertions)]
 #[derive(Debug, Default)]
 pub struct DcRefCell<T: ?Sized>(RefCell<T>);
@@ -27,18 +43,33 @@ pub struct DcRefCell<T: ?Sized>(RefCell<T>);
 ///
 /// To simplify debugging and finding errors, all the operations whose checks only run at debug are marked as `unsafe`.
 ///
-/// For this reason the type doesn't implement `Clone`, `Eq`, `Ord`, `PartialEq` nor `PartialOrd` unlike `RefCell<T>`, as they would be unsafe but we can't mark them as such.
+/// For this reason the type doesn't implement `Clone`, `Eq`, `Ord`, `PartialEq`, `PartialOrd` nor `Serialize` unlike `RefCell<T>`, as they would be unsafe but we can't mark them as such.
+/// With the `serde` feature, the `Ref` and `RefMut` guards implement `Serialize` instead, so the value is serialized through the guard returned by `borrow`.
+///
+/// On both Debug and Release, it's `Send` when `T: Send` and never `Sync`, just like `RefCell<T>`.
 #[cfg(not(debug_assertions))]
 #[derive(Default)]
+#[repr(transparent)]
 pub struct DcRefCell<T: ?Sized>(UnsafeCell<T>);
 
-#[cfg(not(debug_assertions))]
+#[cfg(all(not(debug_assertions), not(feature = "debug_contents")))]
 impl<T: ?Sized> Debug for DcRefCell<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.debug_struct("DcRefCell").finish_non_exhaustive()
     }
 }
 
+/// With the `debug_contents` feature, the name of the wrapped type is printed on Release too.
+///
+/// The value itself isn't printed as there is no borrow state on Release to know if reading it is sound, use `DcRefCell::debug_contents_dc` for that.
+#[cfg(all(not(debug_assertions), feature = "debug_contents"))]
+impl<T: ?Sized> Debug for DcRefCell<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("DcRefCell").field("type", &any::type_name::<T>()).finish_non_exhaustive()
+    }
+}
+
+// Declared for both profiles so auto-traits don't depend on whether the inner cell is a `RefCell<T>` or an `UnsafeCell<T>`.
 impl<T: ?Sized> !Sync for DcRefCell<T> {}
 
 unsafe impl<T: ?Sized> Send for DcRefCell<T> where T: Send {}
@@ -53,7 +84,77 @@ impl<T> From<T> for DcRefCell<T> {
     }
 }
 
-impl<T: ?Sized + Clone> DcRefCell<T> {
+#[cfg(debug_assertions)]
+impl<T> From<RefCell<T>> for DcRefCell<T> {
+    /// Creates a new `DcRefCell<T>` from a `RefCell<T>`.
+    #[inline]
+    fn from(cell: RefCell<T>) -> Self {
+        Self(cell)
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<T> From<RefCell<T>> for DcRefCell<T> {
+    /// Creates a new `DcRefCell<T>` from a `RefCell<T>`.
+    #[inline(always)]
+    fn from(cell: RefCell<T>) -> Self {
+        Self::new(cell.into_inner())
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<T> From<UnsafeCell<T>> for DcRefCell<T> {
+    /// Creates a new `DcRefCell<T>` from an `UnsafeCell<T>`.
+    #[inline]
+    fn from(cell: UnsafeCell<T>) -> Self {
+        Self::new(cell.into_inner())
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<T> From<UnsafeCell<T>> for DcRefCell<T> {
+    /// Creates a new `DcRefCell<T>` from an `UnsafeCell<T>`.
+    #[inline(always)]
+    fn from(cell: UnsafeCell<T>) -> Self {
+        Self(cell)
+    }
+}
+
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for DcRefCell<T> {
+    /// Deserializes a new `DcRefCell<T>` containing the deserialized value.
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+
+    /// Deserializes the value in place into the contents of `place`, reusing its allocations when `T` supports it.
+    ///
+    /// As `place` is mutably borrowed, the cell can't be borrowed during the deserialization, so this is safe and doesn't need checks on Debug.
+    #[inline]
+    fn deserialize_in_place<D: serde::Deserializer<'de>>(deserializer: D, place: &mut Self) -> std::result::Result<(), D::Error> {
+        T::deserialize_in_place(deserializer, place.get_mut())
+    }
+}
+
+impl<T: Default> DcRefCell<T> {
+    /// Creates a new `DcRefCell` containing the default value of `T`.
+    ///
+    /// This is equivalent to the `Default` implementation of `DcRefCell<T>`, but can be called without naming the trait.
+    ///
+    /// It can be called in const contexts when `T` implements `Default` as a const trait (such as `Vec<T>`, `String` or the integers),
+    /// which requires the calling crate to enable the nightly `const_trait_impl` feature.
+    #[inline(always)]
+    pub const fn with_default() -> Self
+    where
+        T: [const] Default,
+    {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Clone> DcRefCell<T> {
     /// Returns a copy of the value.
     ///
     /// # Panics (Debug)
@@ -91,8 +192,11 @@ impl<T: ?Sized + Clone> DcRefCell<T> {
     ///
     /// Since this method borrows `DcRefCell` mutably, it is statically guaranteed that no borrows to the underlying data exist.
     /// The dynamic checks (at Debug) inherent in `clone` and most other methods of `DcRefCell` are therefore unnecessary.
+    ///
+    /// It has no safety requirements, it's only `unsafe` to keep the signature its existing callers use.
     #[inline(always)]
     #[cfg_attr(debug_assertions, track_caller)]
+    #[allow(clippy::missing_safety_doc)]
     pub unsafe fn clone_mut(&mut self) -> Self {
         Self::new(self.get_mut().clone())
     }
@@ -344,6 +448,40 @@ impl<T: ?Sized + Ord> DcRefCell<T> {
     }
 }
 
+#[cfg(feature = "debug_contents")]
+impl<T: ?Sized + Debug> DcRefCell<T> {
+    /// Immutably borrows the wrapped value to format it with `Debug`.
+    ///
+    /// The borrow lasts until the returned value exits scope.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn debug_contents_dc(&self) -> impl Debug + '_ {
+        DebugContents(self.borrow())
+    }
+}
+
+/// Formats the borrowed value of a `DcRefCell<T>` without the `Ref` wrapper.
+#[cfg(feature = "debug_contents")]
+struct DebugContents<'b, T: ?Sized>(Ref<'b, T>);
+
+#[cfg(feature = "debug_contents")]
+impl<T: ?Sized + Debug> Debug for DebugContents<'_, T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&*self.0, f)
+    }
+}
+
 impl<T: ?Sized> DcRefCell<T> {
     /// Returns a mutable reference to the underlying data.
     ///
@@ -356,10 +494,97 @@ impl<T: ?Sized> DcRefCell<T> {
     ///
     /// In most situations where `DcRefCell` is used, it can’t be borrowed mutably.
     /// Use `borrow_mut` to get mutable access to the underlying data then.
+    ///
+    /// This is available for unsized values (such as `DcRefCell<[T]>` or `DcRefCell<dyn Trait>`) on both Debug and Release.
+    ///
+    /// The returned reference borrows `self` mutably, so the borrow checker rejects any `borrow` or `borrow_mut` while it's alive, on both Debug and Release.
+    /// Once it's dropped, borrowing through `&self` is valid again.
+    /// Note that this doesn't reset the borrow state on Debug: if a `Ref` or `RefMut` was leaked (e.g. with `mem::forget`), later borrows will still panic on Debug even though `get_mut` succeeded.
     #[inline(always)]
     pub fn get_mut(&mut self) -> &mut T {
         self.0.get_mut()
     }
+
+    /// Swaps the wrapped value of self with the wrapped value of other, without deinitializing either one.
+    ///
+    /// Unlike `swap`, this works for unsized values (such as `DcRefCell<dyn Trait>` or `DcRefCell<[T]>`) as long as both values share the same pointer metadata (the same vtable or the same length).
+    /// The payloads are swapped byte by byte.
+    ///
+    /// Note that two vtables of the same concrete type are not guaranteed to be unique, so the check at Debug may reject values of the same type.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value in either `DcRefCell` is currently borrowed, point to the same `DcRefCell` or if the metadata of both values doesn't match.
+    ///
+    /// # Safety
+    ///
+    /// Value in either `DcRefCell` must not be currently mutably borrowed nor point to the same `DcRefCell`.
+    /// Both values must have the same metadata.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[cfg_attr(debug_assertions, inline)]
+    #[cfg_attr(not(debug_assertions), inline(always))]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn swap_unsized_dc(&self, other: &Self) {
+        let mut a = self.borrow_mut();
+        let mut b = other.borrow_mut();
+        let a: &mut T = &mut a;
+        let b: &mut T = &mut b;
+        #[cfg(debug_assertions)]
+        assert!(
+            ptr::metadata(a) == ptr::metadata(b),
+            "values of `DcRefCell` have mismatched metadata ({:?} and {:?})",
+            ptr::metadata(a),
+            ptr::metadata(b)
+        );
+        ptr::swap_nonoverlapping(a as *mut T as *mut u8, b as *mut T as *mut u8, mem::size_of_val(a));
+    }
+
+    /// Immutably borrows the wrapped value of a pinned `DcRefCell`, keeping the value pinned.
+    ///
+    /// The borrow lasts until the returned `Ref` exits scope.
+    /// Multiple immutable borrows can be taken out at the same time.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    ///
+    /// Also, since the value is structurally pinned by the `DcRefCell`, it must never be moved out of the cell after it was pinned
+    /// (for example by `replace`, `swap` or `into_inner`), unless `T: Unpin`.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn borrow_pinned_dc(self: Pin<&Self>) -> Pin<Ref<'_, T>> {
+        Pin::new_unchecked(self.get_ref().borrow())
+    }
+
+    /// Mutability borrows the wrapped value of a pinned `DcRefCell`, keeping the value pinned.
+    ///
+    /// The borrow lasts until the returned `RefMut` or all `RefMuts` derived from it exit scope.
+    /// The value cannot be borrowed while this borrow is active.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    ///
+    /// Also, since the value is structurally pinned by the `DcRefCell`, it must never be moved out of the cell after it was pinned
+    /// (for example by `replace`, `swap` or `into_inner`), unless `T: Unpin`.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn borrow_mut_pinned_dc(self: Pin<&Self>) -> Pin<RefMut<'_, T>> {
+        Pin::new_unchecked(self.get_ref().borrow_mut())
+    }
 }
 
 impl<T> DcRefCell<T> {
@@ -446,49 +671,212 @@ impl<T> DcRefCell<T> {
         mem::replace(mut_borrow, replacement)
     }
 
+    /// Replaces the wrapped value with a new one computed from `f`, returning the old value, without deinitializing either one.
+    ///
+    /// Since `f` only works on the value in place, if it panics the panic is propagated and the cell keeps its current value (including any modification `f` did before panicking).
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn replace_with_keep_dc<F: FnOnce(&mut T) -> T>(&self, f: F) -> T {
+        let mut borrow = self.borrow_mut();
+        let replacement = f(&mut borrow);
+        mem::replace(&mut *borrow, replacement)
+    }
+
+    /// Replaces the wrapped value with a new one computed from `f`, returning the old value, without deinitializing either one.
+    ///
+    /// Since `f` only works on the value in place, if it panics the panic is propagated and the cell keeps its current value (including any modification `f` did before panicking).
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if the value is currently borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn replace_with_keep_dc_expect<F: FnOnce(&mut T) -> T>(&self, f: F, msg: &str) -> T {
+        let mut borrow = self.borrow_mut_expect(msg);
+        let replacement = f(&mut borrow);
+        mem::replace(&mut *borrow, replacement)
+    }
+
+    /// Replaces the wrapped value with a new one computed from `f`, which takes the current value by value.
+    ///
+    /// This is `replace_with_dc` applied to the contents of the cell: while `f` runs, the cell is logically empty.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Abort (Debug)
+    ///
+    /// It aborts if `f` panics, so the cell is never left empty.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed and `f` shouldn't panic.
+    ///
+    /// Failing any of these produces undefined behavior on Release.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn replace_with_owned_dc<F: FnOnce(T) -> T>(&self, f: F) {
+        replace_with_dc(&mut *self.borrow_mut(), f);
+    }
+
+    /// Immutably borrows a component of the wrapped value, projected by `f`.
+    ///
+    /// This is a shorthand for `Ref::map(self.borrow(), f)`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn borrow_field_dc<U: ?Sized, F: FnOnce(&T) -> &U>(&self, f: F) -> Ref<'_, U> {
+        Ref::map(self.borrow(), f)
+    }
+
+    /// Immutably borrows a component of the wrapped value, projected by `f`.
+    ///
+    /// This is a shorthand for `Ref::map(self.borrow_expect(msg), f)`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if the value is currently mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn borrow_field_dc_expect<U: ?Sized, F: FnOnce(&T) -> &U>(&self, f: F, msg: &str) -> Ref<'_, U> {
+        Ref::map(self.borrow_expect(msg), f)
+    }
+
     /// Swaps the wrapped value of self with the wrapped value of other, without deinitializing either one.
     ///
     /// This function corresponds to `mem::swap`.
     ///
+    /// Both cells are mutably borrowed before any of them is mutated, first `self` and then `other`.
+    ///
     /// # Panics (Debug)
     ///
-    /// Panics if the value in either `DcRefCell` is currently borrowed or point to the same `DcRefCell`.
+    /// Panics if both point to the same `DcRefCell`, or if the value in either `DcRefCell` is currently borrowed, stating which one.
     ///
     /// # Safety
     ///
-    /// Value in either `DcRefCell` must not be currently mutably borrowed.
+    /// Value in either `DcRefCell` must not be currently borrowed nor point to the same `DcRefCell`.
     ///
     /// Failing this produces undefined behavior on Release.
-    #[inline(always)]
+    #[cfg_attr(debug_assertions, inline)]
+    #[cfg_attr(not(debug_assertions), inline(always))]
     #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn swap(&self, other: &Self) {
-        mem::swap(&mut *self.borrow_mut(), &mut *other.borrow_mut());
+        #[cfg(debug_assertions)]
+        let (mut a, mut b) = {
+            assert!(!ptr::eq(self, other), "cannot swap a `DcRefCell` with itself");
+            (self.borrow_mut_for_swap("first (`self`)", None), other.borrow_mut_for_swap("second (`other`)", None))
+        };
+        #[cfg(not(debug_assertions))]
+        let (mut a, mut b) = (self.borrow_mut(), other.borrow_mut());
+        mem::swap(&mut *a, &mut *b);
     }
 
     /// Swaps the wrapped value of self with the wrapped value of other, without deinitializing either one.
     ///
     /// This function corresponds to `mem::swap`.
     ///
+    /// Both cells are mutably borrowed before any of them is mutated, first `self` and then `other`.
+    ///
     /// # Panics (Debug)
     ///
-    /// Panics if the value in either `DcRefCell` is currently borrowed or point to the same `DcRefCell`.
+    /// Panics with the specified message if both point to the same `DcRefCell`, or if the value in either `DcRefCell` is currently borrowed, stating which one.
     ///
     /// # Safety
     ///
-    /// Value in either `DcRefCell` must not be currently mutably borrowed.
+    /// Value in either `DcRefCell` must not be currently borrowed nor point to the same `DcRefCell`.
     ///
     /// Failing this produces undefined behavior on Release.
-    #[inline(always)]
+    #[cfg_attr(debug_assertions, inline)]
+    #[cfg_attr(not(debug_assertions), inline(always))]
     #[cfg_attr(debug_assertions, track_caller)]
     pub unsafe fn swap_expect(&self, other: &Self, msg: &str) {
-        mem::swap(&mut *self.borrow_mut_expect(msg), &mut *other.borrow_mut_expect(msg));
+        #[cfg(debug_assertions)]
+        let (mut a, mut b) = {
+            assert!(!ptr::eq(self, other), "{}: cannot swap a `DcRefCell` with itself", msg);
+            (self.borrow_mut_for_swap("first (`self`)", Some(msg)), other.borrow_mut_for_swap("second (`other`)", Some(msg)))
+        };
+        #[cfg(not(debug_assertions))]
+        let (mut a, mut b) = (self.borrow_mut_expect(msg), other.borrow_mut_expect(msg));
+        mem::swap(&mut *a, &mut *b);
+    }
+}
+
+impl<T: ?Sized> DcRefCell<Box<T>> {
+    /// Replaces the wrapped box with a new one, returning the old box.
+    ///
+    /// This allows replacing boxed unsized values (such as `Box<dyn Trait>`) stored in the cell.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn replace_boxed_dc(&self, new: Box<T>) -> Box<T> {
+        mem::replace(&mut *self.borrow_mut(), new)
+    }
+
+    /// Replaces the wrapped box with a new one, returning the old box.
+    ///
+    /// This allows replacing boxed unsized values (such as `Box<dyn Trait>`) stored in the cell.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if the value is currently borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    pub unsafe fn replace_boxed_dc_expect(&self, new: Box<T>, msg: &str) -> Box<T> {
+        mem::replace(&mut *self.borrow_mut_expect(msg), new)
     }
 }
 
 #[cfg(debug_assertions)]
 impl<T: ?Sized> DcRefCell<T> {
     /// Returns a raw pointer to the underlying data in this cell.
-    #[inline(always)]
+    #[inline]
     pub fn as_ptr(&self) -> *mut T {
         self.0.as_ptr()
     }
@@ -507,7 +895,7 @@ impl<T: ?Sized> DcRefCell<T> {
     /// Value must not be currently mutably borrowed.
     ///
     /// Failing this produces undefined behavior on Release.
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     pub unsafe fn borrow(&self) -> Ref<'_, T> {
         Ref(self.0.try_borrow().unwrap())
@@ -527,12 +915,104 @@ impl<T: ?Sized> DcRefCell<T> {
     /// Value must not be currently mutably borrowed.
     ///
     /// Failing this produces undefined behavior on Release.
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     pub unsafe fn borrow_expect(&self, msg: &str) -> Ref<'_, T> {
         Ref(self.0.try_borrow().expect(msg))
     }
 
+    /// Immutably borrows the wrapped value, limiting how many of these borrows of the cell can be alive at the same time.
+    ///
+    /// This is intended to catch runaway recursion in algorithms that borrow the same cell at each level.
+    /// On Debug, the borrow depth of the cell is increased while the returned guard is alive.
+    /// Only borrows taken out with this method count towards the depth.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently mutably borrowed, or if the borrow depth of the cell would exceed `max_depth`.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn borrow_depth_limited_dc(&self, max_depth: usize) -> DepthLimitedRef<'_, T> {
+        DepthLimitedRef::new(self.0.try_borrow().unwrap(), ptr::from_ref(self).addr(), max_depth)
+    }
+
+    /// Immutably borrows the wrapped value, logging borrow conflicts instead of panicking.
+    ///
+    /// This method is intended only for diagnostics, to gather multiple borrow conflicts in a single run.
+    /// On Debug, if the value is currently mutably borrowed, a warning with the caller location is printed to the standard error
+    /// and the value is accessed anyway without tracking the borrow, exactly as it would on Release.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on both Debug and Release.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn borrow_or_warn(&self) -> Ref<'_, T> {
+        match self.0.try_borrow() {
+            Ok(borrow) => Ref(borrow),
+            Err(error) => {
+                eprintln!("warning: {} at {}, continuing without tracking the borrow", error, Location::caller());
+                thread_local! {
+                    // Never mutably borrowed, so it can produce untracked `Ref`s by mapping its borrows.
+                    static UNTRACKED: &'static RefCell<()> = Box::leak(Box::new(RefCell::new(())));
+                }
+                let untracked: &RefCell<()> = UNTRACKED.with(|cell| *cell);
+                let ptr = self.0.as_ptr();
+                Ref(std::cell::Ref::map(untracked.borrow(), |_| &*ptr))
+            }
+        }
+    }
+
+    /// Immutably borrows the wrapped value, returning a bare reference instead of a `Ref` guard.
+    ///
+    /// Unlike `borrow`, the borrow is not tracked (not even on Debug) and the returned reference is not restricted by `must_not_suspend`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    /// Also, the value must not be mutably borrowed (nor mutated by any other mean) while the returned reference is alive.
+    /// This last requirement is not checked on Debug.
+    ///
+    /// Failing this produces undefined behavior on both Debug and Release.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn borrow_unguarded_dc(&self) -> &T {
+        self.0.try_borrow_unguarded().unwrap()
+    }
+
+    /// Immutably borrows the wrapped value, returning a bare reference instead of a `Ref` guard.
+    ///
+    /// Unlike `borrow`, the borrow is not tracked (not even on Debug) and the returned reference is not restricted by `must_not_suspend`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if the value is currently mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    /// Also, the value must not be mutably borrowed (nor mutated by any other mean) while the returned reference is alive.
+    /// This last requirement is not checked on Debug.
+    ///
+    /// Failing this produces undefined behavior on both Debug and Release.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn borrow_unguarded_dc_expect(&self, msg: &str) -> &T {
+        self.0.try_borrow_unguarded().expect(msg)
+    }
+
     /// Mutability borrows the wrapped value.
     ///
     /// The borrow lasts until the returned `RefMut` or all `RefMuts` derived from it exit scope.
@@ -547,7 +1027,7 @@ impl<T: ?Sized> DcRefCell<T> {
     /// Value must not be currently mutably borrowed.
     ///
     /// Failing this produces undefined behavior on Release.
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     pub unsafe fn borrow_mut(&self) -> RefMut<'_, T> {
         RefMut(self.0.try_borrow_mut().unwrap())
@@ -567,19 +1047,140 @@ impl<T: ?Sized> DcRefCell<T> {
     /// Value must not be currently mutably borrowed.
     ///
     /// Failing this produces undefined behavior on Release.
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     pub unsafe fn borrow_mut_expect(&self, msg: &str) -> RefMut<'_, T> {
         RefMut(self.0.try_borrow_mut().expect(msg))
     }
+
+    /// Mutability borrows the wrapped value.
+    ///
+    /// The borrow lasts until the returned `RefMut` or all `RefMuts` derived from it exit scope.
+    /// The value cannot be borrowed while this borrow is active.
+    ///
+    /// Unlike `borrow_mut`, a borrow conflict aborts the process instead of panicking, so the faulting frame can be inspected in a core dump.
+    ///
+    /// # Abort (Debug)
+    ///
+    /// Aborts if the value is currently borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn borrow_mut_abort(&self) -> RefMut<'_, T> {
+        match self.0.try_borrow_mut() {
+            Ok(borrow) => RefMut(borrow),
+            Err(error) => {
+                eprintln!("{} at {}", error, Location::caller());
+                process::abort()
+            }
+        }
+    }
+
+    /// Mutably borrows the value for `swap`, naming which of the two cells was already borrowed on failure.
+    #[inline]
+    #[track_caller]
+    fn borrow_mut_for_swap(&self, cell: &str, msg: Option<&str>) -> RefMut<'_, T> {
+        match (self.0.try_borrow_mut(), msg) {
+            (Ok(borrow), _) => RefMut(borrow),
+            (Err(_), None) => panic!("cannot swap: the {} `DcRefCell` is already borrowed", cell),
+            (Err(_), Some(msg)) => panic!("{}: the {} `DcRefCell` is already borrowed", msg, cell),
+        }
+    }
+
+    /// Consumes a boxed `DcRefCell`, returning the wrapped value in a box.
+    ///
+    /// Unlike `into_inner`, this works for unsized values (such as `DcRefCell<[T]>` or `DcRefCell<dyn Trait>`), which is why the cell must be boxed.
+    #[inline]
+    pub fn into_boxed_inner(self: Box<Self>) -> Box<T> {
+        let cell = Box::into_raw(self);
+        // SAFETY: `cell` comes from a `Box`, so it's valid and owns its value.
+        // The value is moved bitwise into a new allocation, and the old allocation is freed without dropping it.
+        unsafe {
+            let value = (*cell).0.as_ptr();
+            let layout = Layout::for_value(&*value);
+            let new = if layout.size() == 0 {
+                ptr::without_provenance_mut::<u8>(layout.align())
+            } else {
+                let new = alloc::alloc(layout);
+                if new.is_null() {
+                    alloc::handle_alloc_error(layout);
+                }
+                new
+            };
+            ptr::copy_nonoverlapping(value as *const u8, new, layout.size());
+            // The borrow flag of `RefCell` makes the layout of the cell non-zero sized.
+            alloc::dealloc(cell as *mut u8, Layout::for_value(&*cell));
+            Box::from_raw(ptr::from_raw_parts_mut(new, ptr::metadata(value)))
+        }
+    }
+
+    /// Undoes the effect of leaked guards on the borrow state of the `DcRefCell`, returning a mutable reference to the underlying data.
+    ///
+    /// This is useful to recover a `DcRefCell` after `Ref::leak` or `RefMut::leak` were used.
+    /// Since this method borrows `DcRefCell` mutably, it is statically guaranteed that no live borrows exist.
+    ///
+    /// On Release, there is no borrow state, so this is equivalent to `get_mut`.
+    #[inline]
+    #[cfg(feature = "cell_leak")]
+    pub fn undo_leak(&mut self) -> &mut T {
+        self.0.undo_leak()
+    }
+
+    /// Mutably borrows the wrapped value for the duration of `f`, returning its result.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Abort (Debug)
+    ///
+    /// It aborts if `f` panics.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed and `f` shouldn't panic.
+    ///
+    /// Failing the former produces undefined behavior on Release.
+    /// Failing the latter unwinds without aborting on Release.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn with_borrow_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut borrow = self.borrow_mut();
+        panic::catch_unwind(panic::AssertUnwindSafe(|| f(&mut borrow))).unwrap_or_else(|_| process::abort())
+    }
+
+    /// Mutably borrows the wrapped value and runs `on_ok` with it, returning its result.
+    ///
+    /// On Debug, if the value is currently borrowed, `on_conflict` is run instead of panicking, so the caller can log or skip the update.
+    /// On Release, the borrow is never checked, so `on_ok` is always run.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed.
+    ///
+    /// Failing this runs `on_conflict` on Debug, but produces undefined behavior on Release.
+    #[inline]
+    pub unsafe fn borrow_mut_or_else_dc<R>(&self, on_conflict: impl FnOnce() -> R, on_ok: impl FnOnce(&mut T) -> R) -> R {
+        match self.0.try_borrow_mut() {
+            Ok(mut borrow) => on_ok(&mut borrow),
+            Err(_) => on_conflict(),
+        }
+    }
 }
 
 
 #[cfg(debug_assertions)]
 impl<T> DcRefCell<T> {
     /// Creates a new `DcRefCell` containing `value.`
-    #[inline(always)]
-    pub fn new(value: T) -> Self {
+    ///
+    /// This function is `const`, so it can be used to initialize constants, statics and `thread_local!` values.
+    #[inline]
+    pub const fn new(value: T) -> Self {
         Self(RefCell::new(value))
     }
 }
@@ -629,10 +1230,90 @@ impl<T: ?Sized> DcRefCell<T> {
     /// Failing this produces undefined behavior on Release.
     #[inline(always)]
     #[track_caller]
-    pub unsafe fn borrow_expect(&self, msg: &str) -> Ref<'_, T> {
+    pub unsafe fn borrow_expect(&self, _msg: &str) -> Ref<'_, T> {
+        Ref(&*self.0.get())
+    }
+
+    /// Immutably borrows the wrapped value, limiting how many of these borrows of the cell can be alive at the same time.
+    ///
+    /// This is intended to catch runaway recursion in algorithms that borrow the same cell at each level.
+    /// On Debug, the borrow depth of the cell is increased while the returned guard is alive.
+    /// Only borrows taken out with this method count towards the depth.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently mutably borrowed, or if the borrow depth of the cell would exceed `max_depth`.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[track_caller]
+    pub unsafe fn borrow_depth_limited_dc(&self, _max_depth: usize) -> DepthLimitedRef<'_, T> {
+        DepthLimitedRef(&*self.0.get())
+    }
+
+    /// Immutably borrows the wrapped value, logging borrow conflicts instead of panicking.
+    ///
+    /// This method is intended only for diagnostics, to gather multiple borrow conflicts in a single run.
+    /// On Debug, if the value is currently mutably borrowed, a warning with the caller location is printed to the standard error
+    /// and the value is accessed anyway without tracking the borrow, exactly as it would on Release.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    ///
+    /// Failing this produces undefined behavior on both Debug and Release.
+    #[inline(always)]
+    #[track_caller]
+    pub unsafe fn borrow_or_warn(&self) -> Ref<'_, T> {
         Ref(&*self.0.get())
     }
 
+    /// Immutably borrows the wrapped value, returning a bare reference instead of a `Ref` guard.
+    ///
+    /// Unlike `borrow`, the borrow is not tracked (not even on Debug) and the returned reference is not restricted by `must_not_suspend`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    /// Also, the value must not be mutably borrowed (nor mutated by any other mean) while the returned reference is alive.
+    /// This last requirement is not checked on Debug.
+    ///
+    /// Failing this produces undefined behavior on both Debug and Release.
+    #[inline(always)]
+    #[track_caller]
+    pub unsafe fn borrow_unguarded_dc(&self) -> &T {
+        &*self.0.get()
+    }
+
+    /// Immutably borrows the wrapped value, returning a bare reference instead of a `Ref` guard.
+    ///
+    /// Unlike `borrow`, the borrow is not tracked (not even on Debug) and the returned reference is not restricted by `must_not_suspend`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if the value is currently mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently mutably borrowed.
+    /// Also, the value must not be mutably borrowed (nor mutated by any other mean) while the returned reference is alive.
+    /// This last requirement is not checked on Debug.
+    ///
+    /// Failing this produces undefined behavior on both Debug and Release.
+    #[inline(always)]
+    #[track_caller]
+    pub unsafe fn borrow_unguarded_dc_expect(&self, _msg: &str) -> &T {
+        &*self.0.get()
+    }
+
     /// Mutability borrows the wrapped value.
     ///
     /// The borrow lasts until the returned `RefMut` or all `RefMuts` derived from it exit scope.
@@ -669,17 +1350,148 @@ impl<T: ?Sized> DcRefCell<T> {
     /// Failing this produces undefined behavior on Release.
     #[inline(always)]
     #[track_caller]
-    pub unsafe fn borrow_mut_expect(&self, msg: &str) -> RefMut<'_, T> {
+    pub unsafe fn borrow_mut_expect(&self, _msg: &str) -> RefMut<'_, T> {
+        RefMut(NonNull::new_unchecked(self.0.get()), PhantomData)
+    }
+
+    /// Mutability borrows the wrapped value.
+    ///
+    /// The borrow lasts until the returned `RefMut` or all `RefMuts` derived from it exit scope.
+    /// The value cannot be borrowed while this borrow is active.
+    ///
+    /// Unlike `borrow_mut`, a borrow conflict aborts the process instead of panicking, so the faulting frame can be inspected in a core dump.
+    ///
+    /// # Abort (Debug)
+    ///
+    /// Aborts if the value is currently borrowed.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    #[inline(always)]
+    #[track_caller]
+    pub unsafe fn borrow_mut_abort(&self) -> RefMut<'_, T> {
         RefMut(NonNull::new_unchecked(self.0.get()), PhantomData)
     }
+
+    /// Consumes a boxed `DcRefCell`, returning the wrapped value in a box.
+    ///
+    /// Unlike `into_inner`, this works for unsized values (such as `DcRefCell<[T]>` or `DcRefCell<dyn Trait>`), which is why the cell must be boxed.
+    #[inline(always)]
+    pub fn into_boxed_inner(self: Box<Self>) -> Box<T> {
+        // SAFETY: `DcRefCell<T>` is a transparent wrapper over `UnsafeCell<T>`, which is a transparent wrapper over `T`.
+        unsafe { Box::from_raw(Box::into_raw(self) as *mut T) }
+    }
+
+    /// Undoes the effect of leaked guards on the borrow state of the `DcRefCell`, returning a mutable reference to the underlying data.
+    ///
+    /// This is useful to recover a `DcRefCell` after `Ref::leak` or `RefMut::leak` were used.
+    /// Since this method borrows `DcRefCell` mutably, it is statically guaranteed that no live borrows exist.
+    ///
+    /// On Release, there is no borrow state, so this is equivalent to `get_mut`.
+    #[inline(always)]
+    #[cfg(feature = "cell_leak")]
+    pub fn undo_leak(&mut self) -> &mut T {
+        self.0.get_mut()
+    }
+
+    /// Mutably borrows the wrapped value for the duration of `f`, returning its result.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the value is currently borrowed.
+    ///
+    /// # Abort (Debug)
+    ///
+    /// It aborts if `f` panics.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed and `f` shouldn't panic.
+    ///
+    /// Failing the former produces undefined behavior on Release.
+    /// Failing the latter unwinds without aborting on Release.
+    #[inline(always)]
+    pub unsafe fn with_borrow_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut self.borrow_mut())
+    }
+
+    /// Mutably borrows the wrapped value and runs `on_ok` with it, returning its result.
+    ///
+    /// On Debug, if the value is currently borrowed, `on_conflict` is run instead of panicking, so the caller can log or skip the update.
+    /// On Release, the borrow is never checked, so `on_ok` is always run.
+    ///
+    /// # Safety
+    ///
+    /// Value must not be currently borrowed.
+    ///
+    /// Failing this runs `on_conflict` on Debug, but produces undefined behavior on Release.
+    #[inline(always)]
+    pub unsafe fn borrow_mut_or_else_dc<R>(&self, _on_conflict: impl FnOnce() -> R, on_ok: impl FnOnce(&mut T) -> R) -> R {
+        on_ok(&mut *self.0.get())
+    }
 }
 
 
 #[cfg(not(debug_assertions))]
 impl<T> DcRefCell<T> {
     /// Creates a new `DcRefCell` containing `value.`
+    ///
+    /// This function is `const`, so it can be used to initialize constants, statics and `thread_local!` values.
     #[inline(always)]
-    pub fn new(value: T) -> Self {
+    pub const fn new(value: T) -> Self {
         Self(UnsafeCell::new(value))
     }
-}
\ No newline at end of file
+}
+
+/// Mutably borrows several distinct `DcRefCell`s at once, returning a tuple with their `RefMut` guards in the same order.
+///
+/// Each expression is evaluated once and must produce a reference to a `DcRefCell`.
+/// This expands to calls to `DcRefCell::borrow_mut`, so it must be used inside an `unsafe` block.
+///
+/// # Panics (Debug)
+///
+/// Panics if the same cell is passed more than once, naming the positions of both arguments, or if any of the values is currently borrowed.
+///
+/// # Safety
+///
+/// The cells must be pairwise distinct and their values must not be currently borrowed.
+///
+/// Failing this produces undefined behavior on Release.
+#[macro_export]
+macro_rules! borrow_mut_all_dc {
+    ($($cell:expr),+ $(,)?) => {
+        $crate::borrow_mut_all_dc!(@bind [] $($cell),+)
+    };
+    // Each recursion binds the next cell to `cell`, which hygiene keeps distinct from the ones bound in the other recursions.
+    (@bind [$($bound:ident)*] $cell:expr $(, $rest:expr)*) => {
+        match $cell {
+            cell => $crate::borrow_mut_all_dc!(@bind [$($bound)* cell] $($rest),*),
+        }
+    };
+    (@bind [$($bound:ident)*]) => {{
+        $crate::assert_distinct_cells_dc(&[$($crate::DcRefCell::as_ptr($bound) as *const ()),*]);
+        ($($crate::DcRefCell::borrow_mut($bound),)*)
+    }};
+}
+
+/// Used by `borrow_mut_all_dc!` to check that the cells are pairwise distinct, according to the pointers to their values.
+#[cfg(debug_assertions)]
+#[doc(hidden)]
+#[inline]
+#[track_caller]
+pub fn assert_distinct_cells_dc(cells: &[*const ()]) {
+    for (i, a) in cells.iter().enumerate() {
+        if let Some(j) = cells[i + 1..].iter().position(|b| a == b) {
+            panic!("arguments {} and {} of `borrow_mut_all_dc!` are the same `DcRefCell`", i, i + 1 + j);
+        }
+    }
+}
+
+/// Used by `borrow_mut_all_dc!` to check that the cells are pairwise distinct, according to the pointers to their values.
+#[cfg(not(debug_assertions))]
+#[doc(hidden)]
+#[inline(always)]
+pub fn assert_distinct_cells_dc(_cells: &[*const ()]) {}
@@ -23,9 +23,17 @@ impl<'b, T: ?Sized> Deref for Ref<'b, T> {
     type Target = T;
 
     /// Dereferences the value.
+    #[cfg(debug_assertions)]
     #[inline(always)]
     fn deref(&self) -> &Self::Target {
-        self.0.deref()
+        &self.0
+    }
+
+    /// Dereferences the value.
+    #[cfg(not(debug_assertions))]
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.0
     }
 }
 
@@ -37,6 +45,16 @@ impl<T: Display + ?Sized> Display for Ref<'_, T> {
     }
 }
 
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + ?Sized> serde::Serialize for Ref<'_, T> {
+    /// Serializes the borrowed value.
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        T::serialize(self, serializer)
+    }
+}
+
 #[cfg(debug_assertions)]
 impl<'b, T> Ref<'b, T> {
     /// Copies a `Ref`.
@@ -45,7 +63,8 @@ impl<'b, T> Ref<'b, T> {
     ///
     /// This is an associated function that needs to be used as `Ref::clone(...)`.
     /// A `Clone` implementation or a method would interfere with the widespread use of `r.borrow().clone()` to clone the contents of a `DcRefCell`.
-    #[inline(always)]
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
     pub fn clone(orig: &Self) ->Self {
         Ref(std::cell::Ref::clone(&orig.0))
     }
@@ -57,7 +76,7 @@ impl<'b, T> Ref<'b, T> {
     ///
     /// This is an associated function that needs to be used as `Ref::filter_map(...)`.
     /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
-    #[inline(always)]
+    #[inline]
     pub fn filter_map<U: ?Sized, F: FnOnce(&T) -> Option<&U>>(orig: Self, f: F) -> Result<Ref<'b, U>, Self> {
         std::cell::Ref::filter_map(orig.0, f).map(|e| Ref(e)).map_err(|e| Ref(e))
     }
@@ -70,7 +89,7 @@ impl<'b, T> Ref<'b, T> {
     ///
     /// This is an associated function that needs to be used as `Ref::leak(...)`.
     /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
-    #[inline(always)]
+    #[inline]
     #[cfg(feature = "cell_leak")]
     pub fn leak(orig: Self) -> &'b T {
         std::cell::Ref::leak(orig.0)
@@ -82,7 +101,7 @@ impl<'b, T> Ref<'b, T> {
     ///
     /// This is an associated function that needs to be used as `Ref::map(...)`.
     /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
-    #[inline(always)]
+    #[inline]
     pub fn map<U: ?Sized, F: FnOnce(&T) -> &U>(orig: Self, f: F) -> Ref<'b, U> {
         Ref(std::cell::Ref::map(orig.0, f))
     }
@@ -93,7 +112,7 @@ impl<'b, T> Ref<'b, T> {
     ///
     /// This is an associated function that needs to be used as `Ref::map_split(...)`.
     /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
-    #[inline(always)]
+    #[inline]
     pub fn map_split<U: ?Sized, V: ?Sized, F: FnOnce(&T) -> (&U, &V)>(orig: Self, f: F) -> (Ref<'b, U>, Ref<'b, V>) {
         let tuple = std::cell::Ref::map_split(orig.0, f);
         (Ref(tuple.0), Ref(tuple.1))
@@ -109,6 +128,7 @@ impl<'b, T> Ref<'b, T> {
     /// This is an associated function that needs to be used as `Ref::clone(...)`.
     /// A `Clone` implementation or a method would interfere with the widespread use of `r.borrow().clone()` to clone the contents of a `DcRefCell`.
     #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
     pub fn clone(orig: &Self) ->Self {
         Ref(orig.0)
     }
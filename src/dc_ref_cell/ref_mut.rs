@@ -1,4 +1,10 @@
-use std::{marker::{Unsize, PhantomData}, ops::{CoerceUnsized, Deref, DerefMut}, fmt::{Display, Formatter, Error}, ptr::NonNull};
+use std::{cell::Cell, marker::Unsize, ops::{CoerceUnsized, Deref, DerefMut}, fmt::{Display, Formatter, Error}};
+
+#[cfg(debug_assertions)]
+use std::mem;
+
+#[cfg(not(debug_assertions))]
+use std::{marker::PhantomData, ptr::NonNull};
 
 /// A wrapper type for a mutably borrowed value from a `DcRefCell<T>`.
 #[cfg(debug_assertions)]
@@ -22,7 +28,7 @@ impl<'b, T: ?Sized> Deref for RefMut<'b, T> {
     type Target = T;
 
     /// Dereferences the value.
-    #[inline(always)]
+    #[inline]
     fn deref(&self) -> &Self::Target {
         self.0.deref()
     }
@@ -31,7 +37,7 @@ impl<'b, T: ?Sized> Deref for RefMut<'b, T> {
 #[cfg(debug_assertions)]
 impl<'b, T: ?Sized> DerefMut for RefMut<'b, T> {
     /// Mutably dereferences the value.
-    #[inline(always)]
+    #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.0.deref_mut()
     }
@@ -40,7 +46,7 @@ impl<'b, T: ?Sized> DerefMut for RefMut<'b, T> {
 #[cfg(debug_assertions)]
 impl<T: Display + ?Sized> Display for RefMut<'_, T> {
     /// Formats the value using the given formatter.
-    #[inline(always)]
+    #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
         Display::fmt(&*self.0, f)
     }
@@ -55,7 +61,7 @@ impl<'b, T> RefMut<'b, T> {
     ///
     /// This is an associated function that needs to be used as `RefMut::filter_map(...)`.
     /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
-    #[inline(always)]
+    #[inline]
     pub fn filter_map<U: ?Sized, F: FnOnce(&mut T) -> Option<&mut U>>(orig: Self, f: F) -> Result<RefMut<'b, U>, RefMut<'b, T>> {
         std::cell::RefMut::filter_map(orig.0, f).map(|e| RefMut(e)).map_err(|e| RefMut(e))
     }
@@ -68,7 +74,7 @@ impl<'b, T> RefMut<'b, T> {
     ///
     /// This is an associated function that needs to be used as `RefMut::leak(...)`.
     /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
-    #[inline(always)]
+    #[inline]
     #[cfg(feature = "cell_leak")]
     pub fn leak(orig: Self) -> &'b T {
         std::cell::RefMut::leak(orig.0)
@@ -80,7 +86,7 @@ impl<'b, T> RefMut<'b, T> {
     ///
     /// This is an associated function that needs to be used as `Ref::map(...)`.
     /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
-    #[inline(always)]
+    #[inline]
     pub fn map<U: ?Sized, F: FnOnce(&mut T) -> &mut U>(orig: Self, f: F) -> RefMut<'b, U> {
         RefMut(std::cell::RefMut::map(orig.0, f))
     }
@@ -91,9 +97,31 @@ impl<'b, T> RefMut<'b, T> {
     ///
     /// This is an associated function that needs to be used as `RefMut::map_split(...)`.
     /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
-    #[inline(always)]
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the references returned by `f` overlap.
+    /// Safe code can't produce such references, so this only reports closures that alias the value through unsafe code,
+    /// after they have already done so.
+    ///
+    /// Note that it's not checked whether the references actually point inside the borrowed value,
+    /// as they may also point to data it owns indirectly (such as the buffer of a `Vec<T>`).
+    #[inline]
+    #[track_caller]
     pub fn map_split<U: ?Sized, V: ?Sized, F: FnOnce(&mut T) -> (&mut U, &mut V)>(orig: Self, f: F) -> (RefMut<'b, U>, RefMut<'b, V>) {
         let tuple = std::cell::RefMut::map_split(orig.0, f);
+        let a = (&*tuple.0 as *const U).cast::<u8>() as usize;
+        let a_end = a + mem::size_of_val(&*tuple.0);
+        let b = (&*tuple.1 as *const V).cast::<u8>() as usize;
+        let b_end = b + mem::size_of_val(&*tuple.1);
+        assert!(
+            a >= b_end || b >= a_end,
+            "`map_split` returned overlapping references ({:#x}..{:#x} and {:#x}..{:#x})",
+            a,
+            a_end,
+            b,
+            b_end
+        );
         (RefMut(tuple.0), RefMut(tuple.1))
     }
 }
@@ -131,6 +159,16 @@ impl<T: Display + ?Sized> Display for RefMut<'_, T> {
     }
 }
 
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + ?Sized> serde::Serialize for RefMut<'_, T> {
+    /// Serializes the borrowed value.
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        T::serialize(self, serializer)
+    }
+}
+
 #[cfg(not(debug_assertions))]
 impl<'b, T> RefMut<'b, T> {
     /// Makes a new `RefMut` for an optional component of the borrowed data.
@@ -163,7 +201,8 @@ impl<'b, T> RefMut<'b, T> {
     #[inline(always)]
     #[cfg(feature = "cell_leak")]
     pub fn leak(orig: Self) -> &'b T {
-        orig.0
+        // SAFETY: On Release, the pointer is valid for the lifetime of the borrow of the `DcRefCell`.
+        unsafe { orig.0.as_ref() }
     }
 
     /// Makes a new `RefMut` for a component of the borrowed data.
@@ -189,3 +228,35 @@ impl<'b, T> RefMut<'b, T> {
         (RefMut(NonNull::from(a), PhantomData), RefMut(NonNull::from(b), PhantomData))
     }
 }
+
+#[cfg(debug_assertions)]
+impl<'b, T> RefMut<'b, [T]> {
+    /// Converts a `RefMut` of a slice into a `RefMut` of a slice of cells, allowing per-element interior mutability.
+    ///
+    /// The `DcRefCell` remains mutably borrowed until the returned `RefMut` exits scope,
+    /// but the `&[Cell<T>]` obtained through `Deref` can be shared freely to mutate individual elements.
+    ///
+    /// This is an associated function that needs to be used as `RefMut::as_slice_of_cells(...)`.
+    /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
+    #[inline]
+    pub fn as_slice_of_cells(orig: Self) -> RefMut<'b, [Cell<T>]> {
+        // Safety: `Cell<T>` has the same memory layout as `T`, and we hold the only access to the slice.
+        RefMut(std::cell::RefMut::map(orig.0, |slice| unsafe { &mut *(slice as *mut [T] as *mut [Cell<T>]) }))
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<'b, T> RefMut<'b, [T]> {
+    /// Converts a `RefMut` of a slice into a `RefMut` of a slice of cells, allowing per-element interior mutability.
+    ///
+    /// The `DcRefCell` remains mutably borrowed until the returned `RefMut` exits scope,
+    /// but the `&[Cell<T>]` obtained through `Deref` can be shared freely to mutate individual elements.
+    ///
+    /// This is an associated function that needs to be used as `RefMut::as_slice_of_cells(...)`.
+    /// A method would interfere with methods of the same name on the contents of a `DcRefCell` used through `Deref`.
+    #[inline(always)]
+    pub fn as_slice_of_cells(orig: Self) -> RefMut<'b, [Cell<T>]> {
+        // Safety: `Cell<T>` has the same memory layout as `T`, and we hold the only access to the slice.
+        RefMut(unsafe { NonNull::new_unchecked(orig.0.as_ptr() as *mut [Cell<T>]) }, PhantomData)
+    }
+}
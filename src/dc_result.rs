@@ -1,4 +1,21 @@
+use std::fmt;
+
+#[cfg(debug_assertions)]
+use std::fmt::Debug;
+
+#[cfg(not(debug_assertions))]
+use std::process;
+
+#[cfg(all(debug_assertions, feature = "backtrace"))]
+use std::backtrace::Backtrace;
+
+#[cfg(all(not(debug_assertions), feature = "soft_release"))]
+use std::panic::Location;
+
 /// Defines methods to extract the `Ok(T)` of a `Result<T, E>`.
+///
+/// On Debug it's only implemented when `E: Debug`, as it's required to report the unexpected `Err(E)`.
+/// On Release there is no such requirement.
 pub trait DcResultOk {
     /// Result type in `Result<T, E>`.
     type T;
@@ -31,9 +48,189 @@ pub trait DcResultOk {
     ///
     /// Failing this produces undefined behavior on Release.
     unsafe fn expect_dc_ok(self, msg: &str) -> Self::T;
+
+    /// Returns a reference to the ok content of the result without performing checks on release.
+    ///
+    /// As it takes `self` by reference, it can be called through `Deref` (and `DerefMut`) wrappers such as `Box`, `Rc` or `RefMut`.
+    /// The returned reference borrows from `self`, so calls can be chained directly (e.g. `cell.borrow().unwrap_dc_ok_ref().len()`),
+    /// although a temporary guard such as `Ref` must be bound to a variable if the reference must outlive the statement.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enderlook_debug_checked::{DcRefCell, DcResultOk};
+    ///
+    /// let parsed: Result<Vec<u8>, String> = Ok(vec![1, 2]);
+    /// assert_eq!(unsafe { parsed.unwrap_dc_ok_ref().len() }, 2);
+    ///
+    /// // Through the `Ref<'_, Result<Vec<u8>, String>>` returned by `borrow`.
+    /// let cell = DcRefCell::new(parsed);
+    /// assert_eq!(unsafe { cell.borrow().unwrap_dc_ok_ref().len() }, 2);
+    /// ```
+    unsafe fn unwrap_dc_ok_ref(&self) -> &Self::T;
+
+    /// Returns a reference to the ok content of the result without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_dc_ok_ref(&self, msg: &str) -> &Self::T;
+
+    /// Returns a mutable reference to the ok content of the result without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enderlook_debug_checked::{DcRefCell, DcResultOk};
+    ///
+    /// let cell: DcRefCell<Result<String, u32>> = DcRefCell::new(Ok(String::from("a")));
+    /// unsafe { cell.borrow_mut().unwrap_dc_ok_mut().push('b') };
+    /// assert_eq!(unsafe { cell.borrow().unwrap_dc_ok_ref().as_str() }, "ab");
+    /// ```
+    unsafe fn unwrap_dc_ok_mut(&mut self) -> &mut Self::T;
+
+    /// Returns a mutable reference to the ok content of the result without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_dc_ok_mut(&mut self, msg: &str) -> &mut Self::T;
+
+    /// Unwraps the ok content of the result without performing checks on release, alongside an `OkWitness` proving the extraction happened.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_dc_ok_witness(self) -> (Self::T, OkWitness);
+
+    /// Unwraps the ok content of the result without performing checks on release, with a context computed lazily from the error for the panic message.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the `Display` output of the value returned by `f` if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn with_context_dc_ok<C: fmt::Display, F: FnOnce(Self::E) -> C>(self, f: F) -> Self::T;
+
+    /// Unwraps the ok content of the result without performing checks on release.
+    ///
+    /// This is the same as `unwrap_dc_ok`, named to contrast it with `unwrap_dc_ok_abort` and `unwrap_dc_ok_panic`:
+    /// - `unwrap_dc_ok_fast`: doesn't check on Release, an `Err(E)` is undefined behavior.
+    /// - `unwrap_dc_ok_abort`: checks on Release and aborts the process on `Err(E)`.
+    /// - `unwrap_dc_ok_panic`: checks on Release and panics on `Err(E)`.
+    ///
+    /// All of them panic on Debug.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_dc_ok_fast(self) -> Self::T;
+
+    /// Unwraps the ok content of the result, aborting the process on release if it's `Err(E)`.
+    ///
+    /// See `unwrap_dc_ok_fast` for a comparison of the release behaviors.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Err(E)`.
+    ///
+    /// # Abort (Release)
+    ///
+    /// Aborts if `self` is `Err(E)`.
+    fn unwrap_dc_ok_abort(self) -> Self::T;
+
+    /// Unwraps the ok content of the result, panicking on release too if it's `Err(E)`.
+    ///
+    /// See `unwrap_dc_ok_fast` for a comparison of the release behaviors.
+    /// On Release, the panic message doesn't include the error, as `E` isn't required to implement `Debug`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is `Err(E)`.
+    fn unwrap_dc_ok_panic(self) -> Self::T;
+
+    /// Maps the error of a result which is expected to be `Ok(T)`, without performing checks on release.
+    ///
+    /// This keeps the `Result<T, F>` type, but on Release `g` is never called, as the result is assumed to be `Ok(T)`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Err(E)`, after applying `g` to the error.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn map_err_dc<F, G: FnOnce(Self::E) -> F>(self, g: G) -> Result<Self::T, F>;
+
+    /// Unwraps the ok content of the result without performing checks on release, with a fallback for the `soft_release` feature.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release, unless the `soft_release` feature is enabled,
+    /// in which case a warning with the caller location is printed to the standard error and `default` is returned.
+    unsafe fn unwrap_dc_ok_or(self, default: Self::T) -> Self::T;
 }
 
 /// Defines methods to extract the `Err(E)` of a `Result<T, E>`.
+///
+/// On Debug it's only implemented when `T: Debug`, as it's required to report the unexpected `Ok(T)`.
+/// On Release there is no such requirement.
 pub trait DcResultErr {
     /// Result type in `Result<T, E>`.
     type T;
@@ -66,6 +263,71 @@ pub trait DcResultErr {
     ///
     /// Failing this produces undefined behavior on Release.
     unsafe fn expect_dc_err(self, msg: &str) -> Self::E;
+
+    /// Returns a reference to the err content of the result without performing checks on release.
+    ///
+    /// As it takes `self` by reference, it can be called through `Deref` (and `DerefMut`) wrappers such as `Box`, `Rc` or `RefMut`.
+    /// The returned reference borrows from `self`, so calls can be chained directly (e.g. `cell.borrow().unwrap_dc_err_ref().len()`),
+    /// although a temporary guard such as `Ref` must be bound to a variable if the reference must outlive the statement.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Ok(T)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Err(E)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enderlook_debug_checked::{DcRefCell, DcResultErr};
+    ///
+    /// let cell: DcRefCell<Result<u32, String>> = DcRefCell::new(Err(String::from("error")));
+    /// assert_eq!(unsafe { cell.borrow().unwrap_dc_err_ref().len() }, 5);
+    /// ```
+    unsafe fn unwrap_dc_err_ref(&self) -> &Self::E;
+
+    /// Returns a reference to the err content of the result without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Ok(T)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Err(E)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_dc_err_ref(&self, msg: &str) -> &Self::E;
+
+    /// Returns a mutable reference to the err content of the result without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Ok(T)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Err(E)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_dc_err_mut(&mut self) -> &mut Self::E;
+
+    /// Returns a mutable reference to the err content of the result without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Ok(T)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Err(E)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_dc_err_mut(&mut self, msg: &str) -> &mut Self::E;
 }
 
 #[cfg(debug_assertions)]
@@ -73,16 +335,100 @@ impl<T, E: std::fmt::Debug> DcResultOk for Result<T, E> {
     type T = T;
     type E = E;
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn unwrap_dc_ok(self) -> Self::T {
-        self.unwrap()
+        match self {
+            Ok(value) => value,
+            Err(error) => unwrap_failed("called `Result::unwrap()` on an `Err` value", &error),
+        }
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn expect_dc_ok(self, msg: &str) -> Self::T {
-        self.expect(msg)
+        match self {
+            Ok(value) => value,
+            Err(error) => unwrap_failed(msg, &error),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_ok_ref(&self) -> &Self::T {
+        self.as_ref().unwrap()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_dc_ok_ref(&self, msg: &str) -> &Self::T {
+        self.as_ref().expect(msg)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_ok_mut(&mut self) -> &mut Self::T {
+        self.as_mut().unwrap()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_dc_ok_mut(&mut self, msg: &str) -> &mut Self::T {
+        self.as_mut().expect(msg)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_ok_witness(self) -> (Self::T, OkWitness) {
+        (self.unwrap(), OkWitness(()))
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn with_context_dc_ok<C: fmt::Display, F: FnOnce(Self::E) -> C>(self, f: F) -> Self::T {
+        match self {
+            Ok(value) => value,
+            Err(error) => panic!("{}", f(error)),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_ok_fast(self) -> Self::T {
+        self.unwrap_dc_ok()
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unwrap_dc_ok_abort(self) -> Self::T {
+        // SAFETY: The check is always performed on Debug.
+        unsafe { self.unwrap_dc_ok() }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn unwrap_dc_ok_panic(self) -> Self::T {
+        // SAFETY: The check is always performed on Debug.
+        unsafe { self.unwrap_dc_ok() }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn map_err_dc<F, G: FnOnce(Self::E) -> F>(self, g: G) -> Result<Self::T, F> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => {
+                let description = format!("{:?}", error);
+                g(error);
+                unwrap_failed("called `map_err_dc` on an `Err` value", &format_args!("{}", description))
+            }
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_ok_or(self, _default: Self::T) -> Self::T {
+        self.unwrap_dc_ok()
     }
 }
 
@@ -91,21 +437,56 @@ impl<T: std::fmt::Debug, E> DcResultErr for Result<T, E> {
     type T = T;
     type E = E;
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn unwrap_dc_err(self) -> Self::E {
         self.unwrap_err()
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn expect_dc_err(self, msg: &str) -> Self::E {
         self.expect_err(msg)
     }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_err_ref(&self) -> &Self::E {
+        self.as_ref().unwrap_err()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_dc_err_ref(&self, msg: &str) -> &Self::E {
+        self.as_ref().expect_err(msg)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_err_mut(&mut self) -> &mut Self::E {
+        self.as_mut().unwrap_err()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn expect_dc_err_mut(&mut self, msg: &str) -> &mut Self::E {
+        self.as_mut().expect_err(msg)
+    }
+}
+
+/// Panics with `msg` and the `Debug` representation of `error`, followed by a backtrace if the `backtrace` feature is enabled.
+#[cfg(debug_assertions)]
+#[cold]
+#[track_caller]
+pub(crate) fn unwrap_failed(msg: &str, error: &dyn Debug) -> ! {
+    #[cfg(feature = "backtrace")]
+    panic!("{}: {:?}\n\nbacktrace:\n{}", msg, error, Backtrace::force_capture());
+    #[cfg(not(feature = "backtrace"))]
+    panic!("{}: {:?}", msg, error);
 }
 
 #[cfg(not(debug_assertions))]
-impl<T, E: std::fmt::Debug> DcResultOk for Result<T, E> {
+impl<T, E> DcResultOk for Result<T, E> {
     type T = T;
     type E = E;
 
@@ -118,10 +499,86 @@ impl<T, E: std::fmt::Debug> DcResultOk for Result<T, E> {
     unsafe fn expect_dc_ok(self, _msg: &str) -> Self::T {
         self.unwrap_unchecked()
     }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_ok_ref(&self) -> &Self::T {
+        self.as_ref().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_dc_ok_ref(&self, _msg: &str) -> &Self::T {
+        self.as_ref().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_ok_mut(&mut self) -> &mut Self::T {
+        self.as_mut().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_dc_ok_mut(&mut self, _msg: &str) -> &mut Self::T {
+        self.as_mut().unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_ok_witness(self) -> (Self::T, OkWitness) {
+        (self.unwrap_unchecked(), OkWitness(()))
+    }
+
+    #[inline(always)]
+    unsafe fn with_context_dc_ok<C: fmt::Display, F: FnOnce(Self::E) -> C>(self, _f: F) -> Self::T {
+        self.unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_ok_fast(self) -> Self::T {
+        self.unwrap_unchecked()
+    }
+
+    #[inline(always)]
+    fn unwrap_dc_ok_abort(self) -> Self::T {
+        match self {
+            Ok(value) => value,
+            Err(_) => process::abort(),
+        }
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn unwrap_dc_ok_panic(self) -> Self::T {
+        match self {
+            Ok(value) => value,
+            Err(_) => panic!("called `Result::unwrap()` on an `Err` value"),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn map_err_dc<F, G: FnOnce(Self::E) -> F>(self, _g: G) -> Result<Self::T, F> {
+        Ok(self.unwrap_unchecked())
+    }
+
+    #[cfg(not(feature = "soft_release"))]
+    #[inline(always)]
+    unsafe fn unwrap_dc_ok_or(self, _default: Self::T) -> Self::T {
+        self.unwrap_unchecked()
+    }
+
+    #[cfg(feature = "soft_release")]
+    #[inline]
+    #[track_caller]
+    unsafe fn unwrap_dc_ok_or(self, default: Self::T) -> Self::T {
+        match self {
+            Ok(value) => value,
+            Err(_) => {
+                eprintln!("warning: called `unwrap_dc_ok_or` on an `Err` value at {}, returning the fallback", Location::caller());
+                default
+            }
+        }
+    }
 }
 
 #[cfg(not(debug_assertions))]
-impl<T, E: std::fmt::Debug> DcResultErr for Result<T, E> {
+impl<T, E> DcResultErr for Result<T, E> {
     type T = T;
     type E = E;
 
@@ -134,4 +591,90 @@ impl<T, E: std::fmt::Debug> DcResultErr for Result<T, E> {
     unsafe fn expect_dc_err(self, _msg: &str) -> Self::E {
         self.unwrap_err_unchecked()
     }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_err_ref(&self) -> &Self::E {
+        self.as_ref().unwrap_err_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_dc_err_ref(&self, _msg: &str) -> &Self::E {
+        self.as_ref().unwrap_err_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn unwrap_dc_err_mut(&mut self) -> &mut Self::E {
+        self.as_mut().unwrap_err_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn expect_dc_err_mut(&mut self, _msg: &str) -> &mut Self::E {
+        self.as_mut().unwrap_err_unchecked()
+    }
 }
+
+/// Unwraps the ok content of a `Result<T, E>` expression without performing checks on release.
+///
+/// This expands to `DcResultOk::unwrap_dc_ok(expr)`, so it must be used inside an `unsafe` block.
+/// Since it's expanded at the call site, the panic location on Debug is the location of the macro invocation.
+///
+/// # Panics (Debug)
+///
+/// Panics if the expression is `Err(E)`.
+///
+/// # Safety
+///
+/// The expression must be `Ok(T)`.
+///
+/// Failing this produces undefined behavior on Release.
+#[macro_export]
+macro_rules! ok_dc {
+    ($expr:expr $(,)?) => {
+        $crate::DcResultOk::unwrap_dc_ok($expr)
+    };
+}
+
+/// Unwraps the ok content of `result` without performing checks on release.
+///
+/// This is the free function form of `DcResultOk::unwrap_dc_ok`, which can be referenced as a function item.
+///
+/// # Panics (Debug)
+///
+/// Panics if `result` is `Err(E)`.
+///
+/// # Safety
+///
+/// `result` must be `Ok(T)`.
+///
+/// Failing this produces undefined behavior on Release.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+pub unsafe fn unwrap_dc_ok<T, E: Debug>(result: Result<T, E>) -> T {
+    result.unwrap_dc_ok()
+}
+
+/// Unwraps the ok content of `result` without performing checks on release.
+///
+/// This is the free function form of `DcResultOk::unwrap_dc_ok`, which can be referenced as a function item.
+///
+/// # Panics (Debug)
+///
+/// Panics if `result` is `Err(E)`.
+///
+/// # Safety
+///
+/// `result` must be `Ok(T)`.
+///
+/// Failing this produces undefined behavior on Release.
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub unsafe fn unwrap_dc_ok<T, E>(result: Result<T, E>) -> T {
+    result.unwrap_dc_ok()
+}
+
+/// Zero-sized proof token returned by `DcResultOk::unwrap_dc_ok_witness`.
+///
+/// It can only be obtained from an extraction, so APIs can require it as evidence that a `Result<T, E>` was `Ok(T)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OkWitness(());
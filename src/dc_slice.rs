@@ -1,15 +1,36 @@
-//! Implement extension methods over `[U]`.
+//! Implement extension methods over `[U]` and `Cell<[U]>`.
 //! Using the `slice_index_methods` feature gate it also implement extension methods over `U`.
 
-use std::slice::SliceIndex;
+use std::{cell::Cell, cmp::Ordering, fmt::Debug, ops::Range, ptr, slice::SliceIndex};
+
+use crate::DcCursor;
+
+#[cfg(debug_assertions)]
+use std::{panic::Location, slice::Iter};
+
+#[cfg(not(debug_assertions))]
+use std::hint;
+
+#[cfg(not(debug_assertions))]
+use crate::unreachable_dc;
+
+#[cfg(all(debug_assertions, feature = "heavy_checks"))]
+use crate::same_elements::SameElements;
+
+#[cfg(all(not(debug_assertions), feature = "soft_release"))]
+use std::panic::Location;
 
 /// Defines methods for getting elements at specified indexes without performing check on Release, but panicking on Debug.
+///
+/// On Debug indexes are resolved through `Index`, so out of range indexes panic with the same messages as indexing the slice.
+/// On Release they are resolved through `<[U]>::get`, so slices of zero-sized elements (whose length can reach `usize::MAX`) are handled like any other slice.
+/// The only additional requirement on Release is the one stated by each method: the index must be in range.
 pub trait DcSlice {
     /// Get the element at the specified index without performing checks on release.
     ///
     /// # Panics (Debug)
     ///
-    /// Panics if index is out of range.
+    /// Panics if index is out of range, with the same message as indexing the slice (naming the violated bound of a range and the length of the slice).
     ///
     /// # Safety
     ///
@@ -35,7 +56,7 @@ pub trait DcSlice {
     ///
     /// # Panics (Debug)
     ///
-    /// Panics if index is out of range if index is out of range.
+    /// Panics if index is out of range, with the same message as indexing the slice (naming the violated bound of a range and the length of the slice).
     ///
     /// # Safety
     ///
@@ -60,29 +81,50 @@ pub trait DcSlice {
         index: T,
         msg: &str,
     ) -> &mut T::Output;
+
+    /// Returns whether the specified index is in range, so it can be used with the other methods of this trait.
+    ///
+    /// This performs the check on both Debug and Release.
+    ///
+    /// The index is taken by value, but `Copy` indices (such as `usize`) can be reused after the call.
+    fn contains_index_dc<T: SliceIndex<Self>>(&self, index: T) -> bool;
+
+    /// Get the element at the specified index without performing checks on release, with a fallback for the `soft_release` feature.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if index is out of range, with the same message as indexing the slice.
+    ///
+    /// # Safety
+    ///
+    /// Index should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release, unless the `soft_release` feature is enabled,
+    /// in which case a warning with the caller location is printed to the standard error and `default` is returned.
+    unsafe fn get_dc_or<'a, T: SliceIndex<Self>>(&'a self, index: T, default: &'a T::Output) -> &'a T::Output;
 }
 
 #[cfg(debug_assertions)]
 impl<U> DcSlice for [U] {
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn get_dc<T: SliceIndex<Self>>(&self, index: T) -> &T::Output {
-        self.get(index).unwrap()
+        &self[index]
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn get_expect_dc<T: SliceIndex<Self>>(&self, index: T, msg: &str) -> &T::Output {
         self.get(index).expect(msg)
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn get_dc_mut<T: SliceIndex<Self>>(&mut self, index: T) -> &mut T::Output {
-        self.get_mut(index).unwrap()
+        &mut self[index]
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn get_expect_dc_mut<T: SliceIndex<Self>>(
         &mut self,
@@ -91,6 +133,17 @@ impl<U> DcSlice for [U] {
     ) -> &mut T::Output {
         self.get_mut(index).expect(msg)
     }
+
+    #[inline]
+    fn contains_index_dc<T: SliceIndex<Self>>(&self, index: T) -> bool {
+        self.get(index).is_some()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_dc_or<'a, T: SliceIndex<Self>>(&'a self, index: T, _default: &'a T::Output) -> &'a T::Output {
+        &self[index]
+    }
 }
 
 #[cfg(not(debug_assertions))]
@@ -118,30 +171,54 @@ impl<U> DcSlice for [U] {
     ) -> &mut T::Output {
         self.get_mut(index).unwrap_unchecked()
     }
+
+    #[inline(always)]
+    fn contains_index_dc<T: SliceIndex<Self>>(&self, index: T) -> bool {
+        self.get(index).is_some()
+    }
+
+    #[cfg(not(feature = "soft_release"))]
+    #[inline(always)]
+    unsafe fn get_dc_or<'a, T: SliceIndex<Self>>(&'a self, index: T, _default: &'a T::Output) -> &'a T::Output {
+        self.get(index).unwrap_unchecked()
+    }
+
+    #[cfg(feature = "soft_release")]
+    #[inline]
+    #[track_caller]
+    unsafe fn get_dc_or<'a, T: SliceIndex<Self>>(&'a self, index: T, default: &'a T::Output) -> &'a T::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => {
+                eprintln!("warning: called `get_dc_or` with an index out of range at {}, returning the fallback", Location::caller());
+                default
+            }
+        }
+    }
 }
 
 #[cfg(feature = "slice_index_methods")]
 #[cfg(debug_assertions)]
 impl<U> DcSlice for U {
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn get_dc<T: SliceIndex<Self>>(&self, index: T) -> &T::Output {
-        index.get(self).unwrap()
+        index.index(self)
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn get_expect_dc<T: SliceIndex<Self>>(&self, index: T, msg: &str) -> &T::Output {
         index.get(self).expect(msg)
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn get_dc_mut<T: SliceIndex<Self>>(&mut self, index: T) -> &mut T::Output {
-        index.get_mut(self).unwrap()
+        index.index_mut(self)
     }
 
-    #[inline(always)]
+    #[inline]
     #[track_caller]
     unsafe fn get_expect_dc_mut<T: SliceIndex<Self>>(
         &mut self,
@@ -150,6 +227,17 @@ impl<U> DcSlice for U {
     ) -> &mut T::Output {
         index.get_mut(self).expect(msg)
     }
+
+    #[inline]
+    fn contains_index_dc<T: SliceIndex<Self>>(&self, index: T) -> bool {
+        index.get(self).is_some()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_dc_or<'a, T: SliceIndex<Self>>(&'a self, index: T, _default: &'a T::Output) -> &'a T::Output {
+        index.index(self)
+    }
 }
 
 #[cfg(feature = "slice_index_methods")]
@@ -178,4 +266,1682 @@ impl<U> DcSlice for U {
     ) -> &mut T::Output {
         index.get_mut(self).unwrap_unchecked()
     }
+
+    #[inline(always)]
+    fn contains_index_dc<T: SliceIndex<Self>>(&self, index: T) -> bool {
+        index.get(self).is_some()
+    }
+
+    #[cfg(not(feature = "soft_release"))]
+    #[inline(always)]
+    unsafe fn get_dc_or<'a, T: SliceIndex<Self>>(&'a self, index: T, _default: &'a T::Output) -> &'a T::Output {
+        index.get(self).unwrap_unchecked()
+    }
+
+    #[cfg(feature = "soft_release")]
+    #[inline]
+    #[track_caller]
+    unsafe fn get_dc_or<'a, T: SliceIndex<Self>>(&'a self, index: T, default: &'a T::Output) -> &'a T::Output {
+        match index.get(self) {
+            Some(value) => value,
+            None => {
+                eprintln!("warning: called `get_dc_or` with an index out of range at {}, returning the fallback", Location::caller());
+                default
+            }
+        }
+    }
+}
+
+/// Defines methods for getting cells of the elements of a `Cell<[U]>` at specified indexes without performing check on Release, but panicking on Debug.
+///
+/// For slices of cells (`[Cell<U>]`), `DcSlice::get_dc` already returns a `&Cell<U>`.
+pub trait DcCellSlice {
+    /// Type of the elements in the slice.
+    type Element;
+
+    /// Get the cell of the element at the specified index without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if index is out of range.
+    ///
+    /// # Safety
+    ///
+    /// Index should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_cell_dc(&self, index: usize) -> &Cell<Self::Element>;
+}
+
+#[cfg(debug_assertions)]
+impl<U> DcCellSlice for Cell<[U]> {
+    type Element = U;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_cell_dc(&self, index: usize) -> &Cell<U> {
+        self.as_slice_of_cells().get_dc(index)
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<U> DcCellSlice for Cell<[U]> {
+    type Element = U;
+
+    #[inline(always)]
+    unsafe fn get_cell_dc(&self, index: usize) -> &Cell<U> {
+        self.as_slice_of_cells().get_unchecked(index)
+    }
+}
+
+/// Marker for custom index types (such as newtypes over `usize`) which can be used with `DcSliceElements::get_by_dc`.
+///
+/// The index is converted into `usize` before doing the access.
+pub trait DcIndex: Into<usize> {}
+
+impl DcIndex for usize {}
+
+/// Defines methods over the elements of a slice without performing check on Release, but panicking on Debug.
+pub trait DcSliceElements {
+    /// Type of the elements in the slice.
+    type Element;
+
+    /// Reinterprets the whole slice as a reference to an array of length `N` without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the slice is not `N`.
+    ///
+    /// # Safety
+    ///
+    /// Length of the slice must be exactly `N`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn as_array_dc<const N: usize>(&self) -> &[Self::Element; N];
+
+    /// Reinterprets the whole slice as a mutable reference to an array of length `N` without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the slice is not `N`.
+    ///
+    /// # Safety
+    ///
+    /// Length of the slice must be exactly `N`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn as_array_dc_mut<const N: usize>(&mut self) -> &mut [Self::Element; N];
+
+    /// Splits the slice into a reference to an array with its first `N` elements and a slice with the rest, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the slice is less than `N`.
+    ///
+    /// # Safety
+    ///
+    /// Length of the slice must be at least `N`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn split_first_chunk_dc<const N: usize>(&self) -> (&[Self::Element; N], &[Self::Element]);
+
+    /// Splits the slice into a mutable reference to an array with its first `N` elements and a mutable slice with the rest, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the slice is less than `N`.
+    ///
+    /// # Safety
+    ///
+    /// Length of the slice must be at least `N`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn split_first_chunk_dc_mut<const N: usize>(&mut self) -> (&mut [Self::Element; N], &mut [Self::Element]);
+
+    /// Get the element `k` positions from the end of the slice without performing checks on release.
+    ///
+    /// That is, `k = 0` is the last element.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `k` is not less than the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// `k` must be less than the length of the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_from_end_dc(&self, k: usize) -> &Self::Element;
+
+    /// Get the mutable element `k` positions from the end of the slice without performing checks on release.
+    ///
+    /// That is, `k = 0` is the last element.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `k` is not less than the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// `k` must be less than the length of the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_from_end_dc_mut(&mut self, k: usize) -> &mut Self::Element;
+
+    /// Clones all the elements of the slice into `dst` without performing checks on release.
+    ///
+    /// If a clone panics, the elements already cloned into `dst` are kept and the remaining ones are left untouched.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the slice and `dst` are not equal.
+    ///
+    /// # Safety
+    ///
+    /// Length of the slice and `dst` must be equal.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn clone_into_dc(&self, dst: &mut [Self::Element])
+    where
+        Self::Element: Clone;
+
+    /// Clones all the elements of `src` into the slice without performing checks on release.
+    ///
+    /// If a clone panics, the elements already cloned into the slice are kept and the remaining ones are left untouched.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the slice and `src` are not equal.
+    ///
+    /// # Safety
+    ///
+    /// Length of the slice and `src` must be equal.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn clone_from_slice_dc(&mut self, src: &[Self::Element])
+    where
+        Self::Element: Clone;
+
+    /// Returns an iterator over the first `n` elements of the slice without performing checks on release.
+    ///
+    /// The returned iterator is expected to be fully consumed before being dropped.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `n` is greater than the length of the slice.
+    /// Also, the returned iterator panics on drop if it wasn't fully consumed.
+    ///
+    /// # Safety
+    ///
+    /// `n` must not be greater than the length of the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn take_exact_dc(&self, n: usize) -> impl Iterator<Item = &Self::Element>;
+
+    /// Sorts the slice with a key extraction function, calling the key function only once per element (at Release).
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the key function returns different keys when called twice on the same element.
+    ///
+    /// # Safety
+    ///
+    /// The key function must be deterministic.
+    ///
+    /// Failing this produces an unspecified order of the elements on Release.
+    unsafe fn sort_by_cached_key_dc<K: Ord, F: FnMut(&Self::Element) -> K>(&mut self, f: F);
+
+    /// Returns an iterator over all contiguous windows of length `N` as array references without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `N` is zero or the length of the slice is less than `N`.
+    ///
+    /// # Safety
+    ///
+    /// `N` must not be zero and the length of the slice must be at least `N`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn array_windows_dc<const N: usize>(&self) -> impl Iterator<Item = &[Self::Element; N]>;
+
+    /// Reinterprets the slice as a slice of arrays of length `N` without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `N` is zero or the length of the slice is not a multiple of `N`.
+    ///
+    /// # Safety
+    ///
+    /// `N` must not be zero and the length of the slice must be a multiple of `N`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn as_chunks_array_dc<const N: usize>(&self) -> &[[Self::Element; N]];
+
+    /// Reinterprets the slice as a mutable slice of arrays of length `N` without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `N` is zero or the length of the slice is not a multiple of `N`.
+    ///
+    /// # Safety
+    ///
+    /// `N` must not be zero and the length of the slice must be a multiple of `N`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn as_chunks_array_dc_mut<const N: usize>(&mut self) -> &mut [[Self::Element; N]];
+
+    /// Get the elements at the specified indexes without performing checks on release.
+    ///
+    /// Indexes may be repeated.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if any index is out of range.
+    ///
+    /// # Safety
+    ///
+    /// All indexes should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn gather_dc<const N: usize>(&self, indices: [usize; N]) -> [&Self::Element; N];
+
+    /// Returns the index of the first element equal to `x` without performing checks on release.
+    ///
+    /// On Release, the element is assumed to be present, so the not found path of the scan is removed.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if no element is equal to `x`.
+    ///
+    /// # Safety
+    ///
+    /// An element equal to `x` must be present.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn find_dc(&self, x: &Self::Element) -> usize
+    where
+        Self::Element: PartialEq;
+
+    /// Returns an iterator over subslices separated by elements that match `pred`, each of them ending with its delimiter.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the slice is not empty and its last element doesn't match `pred`.
+    ///
+    /// # Safety
+    ///
+    /// The slice must be empty or its last element must match `pred`.
+    ///
+    /// Failing this produces a last subslice without delimiter on Release.
+    unsafe fn split_inclusive_dc<P: FnMut(&Self::Element) -> bool>(&self, pred: P) -> impl Iterator<Item = &[Self::Element]>;
+
+    /// Copies all the elements of `src` into the slice without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the slice and `src` are not equal, or if they overlap in memory.
+    ///
+    /// # Safety
+    ///
+    /// Length of the slice and `src` must be equal, and they must not overlap in memory.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn copy_from_slice_dc(&mut self, src: &[Self::Element])
+    where
+        Self::Element: Copy;
+
+    /// Copies the elements in the range `src` to the position starting at `dest` within the slice, without performing checks on release.
+    ///
+    /// The source and destination ranges may overlap.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `src` is out of range, or if the destination range (`dest..dest + src.len()`) is out of range.
+    ///
+    /// # Safety
+    ///
+    /// Both `src` and the destination range must be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn copy_within_dc(&mut self, src: Range<usize>, dest: usize)
+    where
+        Self::Element: Copy;
+
+    /// Copies the elements in the range `src` to the position starting at `dest` within the slice, without performing checks on release.
+    ///
+    /// Unlike `copy_within_dc`, the source and destination ranges must not overlap, which allows a faster copy.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `src` is out of range, if the destination range (`dest..dest + src.len()`) is out of range, or if both ranges overlap.
+    ///
+    /// # Safety
+    ///
+    /// Both `src` and the destination range must be on range, and they must not overlap.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn copy_within_nonoverlapping_dc(&mut self, src: Range<usize>, dest: usize)
+    where
+        Self::Element: Copy;
+
+    /// Writes successive items of `iter` into the slice without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `iter` yields fewer items than the length of the slice, or if it has items left once the slice is filled.
+    ///
+    /// # Safety
+    ///
+    /// `iter` must yield at least as many items as the length of the slice, and should yield exactly that many.
+    ///
+    /// Yielding fewer items produces undefined behavior on Release.
+    /// Leftover items are silently ignored on Release.
+    unsafe fn fill_from_iter_dc<I: IntoIterator<Item = Self::Element>>(&mut self, iter: I);
+
+    /// Returns an iterator over `n` elements of the slice at a time, alongside the remainder that didn't fit in a whole chunk, without performing checks on release.
+    ///
+    /// Unlike `as_chunks_array_dc`, a non-empty remainder is allowed.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Safety
+    ///
+    /// `n` must not be zero.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn chunks_exact_with_remainder_dc(&self, n: usize) -> (impl Iterator<Item = &[Self::Element]>, &[Self::Element]);
+
+    /// Returns an iterator over `n` elements of the slice at a time, starting at the end of the slice, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `n` is zero or the length of the slice is not a multiple of `n`.
+    ///
+    /// # Safety
+    ///
+    /// `n` must not be zero and the length of the slice should be a multiple of `n`.
+    ///
+    /// Failing the former produces undefined behavior on Release.
+    /// Failing the latter silently skips the leading elements that don't fit in a whole chunk on Release.
+    unsafe fn rchunks_exact_dc(&self, n: usize) -> impl Iterator<Item = &[Self::Element]>;
+
+    /// Returns an iterator over arrays of length `N` of the slice, starting at the end of the slice, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `N` is zero or the length of the slice is not a multiple of `N`.
+    ///
+    /// # Safety
+    ///
+    /// `N` must not be zero and the length of the slice must be a multiple of `N`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn rchunks_array_dc<const N: usize>(&self) -> impl Iterator<Item = &[Self::Element; N]>;
+
+    /// Returns a cursor at the start of the slice, which can be advanced without performing checks on release.
+    ///
+    /// This performs no checks by itself, the checks are done by the methods of `DcCursor`.
+    fn cursor_dc(&self) -> DcCursor<'_, Self::Element>;
+
+    /// Reorders the slice such that the element at `k` is at its final sorted position, without performing checks on release.
+    ///
+    /// Returns the elements before `k`, the element at `k` and the elements after `k`, like `select_nth_unstable`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `k` is out of range.
+    ///
+    /// # Safety
+    ///
+    /// `k` must be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn select_nth_dc(&mut self, k: usize) -> (&mut [Self::Element], &mut Self::Element, &mut [Self::Element])
+    where
+        Self::Element: Ord;
+
+    /// Reorders the slice with the comparator function such that the element at `k` is at its final sorted position, without performing checks on release.
+    ///
+    /// Returns the elements before `k`, the element at `k` and the elements after `k`, like `select_nth_unstable_by`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `k` is out of range.
+    /// Also panics if `compare` doesn't consider an element equal to itself, or if it isn't antisymmetric between adjacent elements.
+    ///
+    /// # Safety
+    ///
+    /// `k` must be on range and `compare` must implement a total order.
+    ///
+    /// Failing the former produces undefined behavior on Release.
+    /// Failing the latter produces an unspecified order on Release.
+    unsafe fn select_nth_by_dc<F: FnMut(&Self::Element, &Self::Element) -> Ordering>(
+        &mut self,
+        k: usize,
+        compare: F,
+    ) -> (&mut [Self::Element], &mut Self::Element, &mut [Self::Element]);
+
+    /// Fills the elements in `range` with clones of `value` without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `range` is out of range or its start is greater than its end.
+    ///
+    /// # Safety
+    ///
+    /// `range` should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn fill_range_dc(&mut self, range: Range<usize>, value: Self::Element)
+    where
+        Self::Element: Clone;
+
+    /// Overwrites the elements in `range` with default values without performing checks on release.
+    ///
+    /// Unlike `fill_range_dc`, this doesn't require the elements to be `Clone`, as a new default value is created for each element.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `range` is out of range or its start is greater than its end.
+    ///
+    /// # Safety
+    ///
+    /// `range` should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn reset_range_dc(&mut self, range: Range<usize>)
+    where
+        Self::Element: Default;
+
+    /// Asserts that the slice is partitioned at `p` by `pred` on Debug.
+    ///
+    /// That is, all the elements before `p` satisfy `pred` and none of the elements from `p` onwards do.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `p` is greater than the length of the slice, or at the first element on the wrong side of `p`, showing its index.
+    ///
+    /// # Safety
+    ///
+    /// `p` must not be greater than the length of the slice and the slice must be partitioned at `p` by `pred`.
+    ///
+    /// Failing this is not detected on Release, as the call is a no-op.
+    unsafe fn assert_partitioned_dc<P: FnMut(&Self::Element) -> bool>(&self, p: usize, pred: P);
+
+    /// Rotates the slice in-place such that the first `mid` elements move to the end, without performing checks on release.
+    ///
+    /// With the `heavy_checks` feature, when the elements are `Ord + Clone`, Debug also verifies that the rotation preserved the elements,
+    /// by comparing sorted copies of the slice from before and after it.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `mid` is greater than the length of the slice, or with the `heavy_checks` feature, if the rotation didn't preserve the elements.
+    ///
+    /// # Safety
+    ///
+    /// `mid` must not be greater than the length of the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn rotate_left_dc(&mut self, mid: usize);
+
+    /// Rotates the slice in-place such that the last `k` elements move to the start, without performing checks on release.
+    ///
+    /// With the `heavy_checks` feature, when the elements are `Ord + Clone`, Debug also verifies that the rotation preserved the elements,
+    /// by comparing sorted copies of the slice from before and after it.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `k` is greater than the length of the slice, or with the `heavy_checks` feature, if the rotation didn't preserve the elements.
+    ///
+    /// # Safety
+    ///
+    /// `k` must not be greater than the length of the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn rotate_right_dc(&mut self, k: usize);
+
+    /// Get the element at the specified custom index without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if index is out of range.
+    ///
+    /// # Safety
+    ///
+    /// Index should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_by_dc<I: DcIndex>(&self, index: I) -> &Self::Element;
+
+    /// Get the mutable element at the specified custom index without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if index is out of range.
+    ///
+    /// # Safety
+    ///
+    /// Index should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_by_dc_mut<I: DcIndex>(&mut self, index: I) -> &mut Self::Element;
+
+    /// Get the element at the index computed by `f` without performing checks on release.
+    ///
+    /// `f` is called exactly once, so expensive index computations can be fed directly into the access.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the index returned by `f` is out of range.
+    ///
+    /// # Safety
+    ///
+    /// The index returned by `f` should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_with_dc<F: FnOnce() -> usize>(&self, f: F) -> &Self::Element;
+
+    /// Get the mutable element at the index computed by `f` without performing checks on release.
+    ///
+    /// `f` is called exactly once, so expensive index computations can be fed directly into the access.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the index returned by `f` is out of range.
+    ///
+    /// # Safety
+    ///
+    /// The index returned by `f` should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_with_dc_mut<F: FnOnce() -> usize>(&mut self, f: F) -> &mut Self::Element;
+
+    /// Divides the slice into two raw slices at `mid`, without performing checks on release.
+    ///
+    /// Unlike `split_at_mut`, this doesn't create mutable references to the halves, so they can later alias through raw pointers without violating the aliasing model.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `mid` is greater than the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// `mid` must not be greater than the length of the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn split_at_raw_dc(&mut self, mid: usize) -> (*mut [Self::Element], *mut [Self::Element]);
+
+    /// Returns an iterator over the rows of the rectangular sub-region `rows` × `cols` of a row-major matrix of width `width` stored in the slice, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `cols` is out of range for `width`, if `rows` is out of range for the number of rows in the slice, or if either range has its start greater than its end.
+    ///
+    /// # Safety
+    ///
+    /// `cols` must be on range for `width` and `rows` must be on range for the number of rows in the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn submatrix_dc(&self, rows: Range<usize>, cols: Range<usize>, width: usize) -> impl Iterator<Item = &[Self::Element]>;
+
+    /// Reorders the slice in-place such that the element at each index `i` becomes the one that was at index `perm[i]`, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `perm` is not a permutation of `0..len`, that is, if its length doesn't match the length of the slice or if any index is out of range or repeated.
+    ///
+    /// # Safety
+    ///
+    /// `perm` must be a permutation of `0..len`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn apply_permutation_dc(&mut self, perm: &[usize]);
+
+    /// Returns an iterator over `expected_chunks` chunks of `n` elements of the slice, without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `n` is zero or the length of the slice is not `n * expected_chunks`.
+    ///
+    /// # Safety
+    ///
+    /// `n` must not be zero and the length of the slice must be `n * expected_chunks`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn chunks_count_dc(&self, n: usize, expected_chunks: usize) -> impl Iterator<Item = &[Self::Element]>;
+
+    /// Informs the compiler that the length of the slice is at least `min_len`, so bounds checks of later accesses below `min_len` can be elided on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the slice is less than `min_len`.
+    ///
+    /// # Safety
+    ///
+    /// The length of the slice must be at least `min_len`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn assume_len_dc(&self, min_len: usize);
+
+    /// Returns the two raw pointers spanning the slice.
+    ///
+    /// This is the same as `as_ptr_range` and performs no checks, as it's always valid, but it's provided alongside `ptr_at_dc` for pointer-based loops.
+    fn as_ptr_range_dc(&self) -> Range<*const Self::Element>;
+
+    /// Returns a raw pointer to the element at the specified index without performing checks on release.
+    ///
+    /// The index may be the length of the slice, producing the pointer one past the last element.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if index is greater than the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// Index must not be greater than the length of the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn ptr_at_dc(&self, index: usize) -> *const Self::Element;
+
+    /// Returns an iterator over the `count` elements starting at `start`, paired with their indexes in the slice, without performing checks on release.
+    ///
+    /// The range is checked once on Debug, so the loop body doesn't perform any bounds check.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `start + count` is greater than the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// `start + count` must not be greater than the length of the slice.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn enumerate_from_dc(&self, start: usize, count: usize) -> impl Iterator<Item = (usize, &Self::Element)>;
+
+    /// Binary searches the slice for `key`, comparing it with the keys extracted from the elements by `f`.
+    ///
+    /// This behaves like `binary_search_by_key`, but on Debug it first checks that the keys are sorted.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics at the first pair of adjacent elements whose keys are out of order, showing their indexes.
+    ///
+    /// # Safety
+    ///
+    /// The slice must be sorted in ascending order by the keys extracted with `f`.
+    ///
+    /// Failing this produces an unspecified result on Release, as with `binary_search_by_key`.
+    unsafe fn binary_search_by_key_dc<K: Ord, F: FnMut(&Self::Element) -> K>(&self, key: &K, f: F) -> Result<usize, usize>;
+
+    /// Get the element at offset `base + index * stride` without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the offset computation overflows or the offset is out of range, showing `index`, `base` and `stride`.
+    ///
+    /// # Safety
+    ///
+    /// `base + index * stride` must not overflow and must be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_strided_dc(&self, index: usize, base: usize, stride: usize) -> &Self::Element;
+
+    /// Get the mutable element at offset `base + index * stride` without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the offset computation overflows or the offset is out of range, showing `index`, `base` and `stride`.
+    ///
+    /// # Safety
+    ///
+    /// `base + index * stride` must not overflow and must be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_strided_dc_mut(&mut self, index: usize, base: usize, stride: usize) -> &mut Self::Element;
+}
+
+#[cfg(debug_assertions)]
+impl<U> DcSliceElements for [U] {
+    type Element = U;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn as_array_dc<const N: usize>(&self) -> &[U; N] {
+        assert!(self.len() == N, "slice of length {} can't be viewed as an array of length {}", self.len(), N);
+        &*(self.as_ptr() as *const [U; N])
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn as_array_dc_mut<const N: usize>(&mut self) -> &mut [U; N] {
+        assert!(self.len() == N, "slice of length {} can't be viewed as an array of length {}", self.len(), N);
+        &mut *(self.as_mut_ptr() as *mut [U; N])
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn split_first_chunk_dc<const N: usize>(&self) -> (&[U; N], &[U]) {
+        assert!(self.len() >= N, "slice of length {} is too short to split off a chunk of length {}", self.len(), N);
+        self.split_first_chunk().unwrap()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn split_first_chunk_dc_mut<const N: usize>(&mut self) -> (&mut [U; N], &mut [U]) {
+        assert!(self.len() >= N, "slice of length {} is too short to split off a chunk of length {}", self.len(), N);
+        self.split_first_chunk_mut().unwrap()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_from_end_dc(&self, k: usize) -> &U {
+        assert!(k < self.len(), "index {} from the end is out of range for slice of length {}", k, self.len());
+        &self[self.len() - 1 - k]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_from_end_dc_mut(&mut self, k: usize) -> &mut U {
+        assert!(k < self.len(), "index {} from the end is out of range for slice of length {}", k, self.len());
+        let len = self.len();
+        &mut self[len - 1 - k]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn clone_into_dc(&self, dst: &mut [U])
+    where
+        U: Clone,
+    {
+        assert!(self.len() == dst.len(), "source slice length ({}) does not match destination slice length ({})", self.len(), dst.len());
+        dst.clone_from_slice(self);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn clone_from_slice_dc(&mut self, src: &[U])
+    where
+        U: Clone,
+    {
+        assert!(src.len() == self.len(), "source slice length ({}) does not match destination slice length ({})", src.len(), self.len());
+        self.clone_from_slice(src);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn take_exact_dc(&self, n: usize) -> impl Iterator<Item = &U> {
+        assert!(n <= self.len(), "can't take {} elements from a slice of length {}", n, self.len());
+        TakeExact {
+            iter: self[..n].iter(),
+            location: Location::caller(),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn sort_by_cached_key_dc<K: Ord, F: FnMut(&U) -> K>(&mut self, mut f: F) {
+        for (i, element) in self.iter().enumerate() {
+            assert!(f(element) == f(element), "key function returned different keys for the element at index {}", i);
+        }
+        self.sort_by_cached_key(f);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn array_windows_dc<const N: usize>(&self) -> impl Iterator<Item = &[U; N]> {
+        assert!(N != 0, "window size must be non-zero");
+        assert!(self.len() >= N, "slice of length {} is shorter than the window size {}", self.len(), N);
+        self.windows(N).map(|window| &*(window.as_ptr() as *const [U; N]))
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn as_chunks_array_dc<const N: usize>(&self) -> &[[U; N]] {
+        assert!(N != 0, "chunk size must be non-zero");
+        assert!(self.len().is_multiple_of(N), "slice of length {} is not a multiple of the chunk size {}", self.len(), N);
+        self.as_chunks_unchecked()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn as_chunks_array_dc_mut<const N: usize>(&mut self) -> &mut [[U; N]] {
+        assert!(N != 0, "chunk size must be non-zero");
+        assert!(self.len().is_multiple_of(N), "slice of length {} is not a multiple of the chunk size {}", self.len(), N);
+        self.as_chunks_unchecked_mut()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn gather_dc<const N: usize>(&self, indices: [usize; N]) -> [&U; N] {
+        for (position, &index) in indices.iter().enumerate() {
+            assert!(index < self.len(), "index {} (at position {}) is out of range for slice of length {}", index, position, self.len());
+        }
+        indices.map(|index| &self[index])
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn find_dc(&self, x: &U) -> usize
+    where
+        U: PartialEq,
+    {
+        self.iter().position(|element| element == x).expect("element not found in slice")
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn split_inclusive_dc<P: FnMut(&U) -> bool>(&self, mut pred: P) -> impl Iterator<Item = &[U]> {
+        if let Some(last) = self.last() {
+            assert!(pred(last), "last element of the slice (at index {}) is not a delimiter", self.len() - 1);
+        }
+        self.split_inclusive(pred)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn copy_from_slice_dc(&mut self, src: &[U])
+    where
+        U: Copy,
+    {
+        assert!(src.len() == self.len(), "source slice length ({}) does not match destination slice length ({})", src.len(), self.len());
+        let dst = self.as_mut_ptr_range();
+        let src_range = src.as_ptr_range();
+        assert!(
+            self.is_empty() || dst.start as *const U >= src_range.end || src_range.start >= dst.end as *const U,
+            "source slice ({:?}) and destination slice ({:?}) overlap",
+            src_range,
+            dst
+        );
+        ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr(), self.len());
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn copy_within_dc(&mut self, src: Range<usize>, dest: usize)
+    where
+        U: Copy,
+    {
+        self.copy_within(src, dest);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn copy_within_nonoverlapping_dc(&mut self, src: Range<usize>, dest: usize)
+    where
+        U: Copy,
+    {
+        assert!(src.start <= src.end && src.end <= self.len(), "source range {:?} out of range for slice of length {}", src, self.len());
+        let count = src.end - src.start;
+        assert!(
+            dest <= self.len() - count,
+            "destination index {} out of range for a copy of {} elements in a slice of length {}",
+            dest,
+            count,
+            self.len()
+        );
+        assert!(
+            count == 0 || src.end <= dest || dest + count <= src.start,
+            "source range {:?} and destination range {:?} overlap",
+            src,
+            dest..dest + count
+        );
+        let ptr = self.as_mut_ptr();
+        ptr::copy_nonoverlapping(ptr.add(src.start), ptr.add(dest), count);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn fill_from_iter_dc<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        let len = self.len();
+        let mut iter = iter.into_iter();
+        for (i, slot) in self.iter_mut().enumerate() {
+            match iter.next() {
+                Some(value) => *slot = value,
+                None => panic!("iterator yielded only {} items but the slice length is {}", i, len),
+            }
+        }
+        assert!(iter.next().is_none(), "iterator yielded more items than the slice length ({})", len);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn chunks_exact_with_remainder_dc(&self, n: usize) -> (impl Iterator<Item = &[U]>, &[U]) {
+        assert!(n != 0, "chunk size must be non-zero");
+        let chunks = self.chunks_exact(n);
+        let remainder = chunks.remainder();
+        (chunks, remainder)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn rchunks_exact_dc(&self, n: usize) -> impl Iterator<Item = &[U]> {
+        assert!(n != 0, "chunk size must be non-zero");
+        assert!(self.len().is_multiple_of(n), "slice of length {} is not a multiple of the chunk size {}", self.len(), n);
+        self.rchunks_exact(n)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn rchunks_array_dc<const N: usize>(&self) -> impl Iterator<Item = &[U; N]> {
+        assert!(N != 0, "chunk size must be non-zero");
+        assert!(self.len().is_multiple_of(N), "slice of length {} is not a multiple of the chunk size {}", self.len(), N);
+        self.as_chunks_unchecked::<N>().iter().rev()
+    }
+
+    #[inline]
+    fn cursor_dc(&self) -> DcCursor<'_, U> {
+        DcCursor::new(self)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn select_nth_dc(&mut self, k: usize) -> (&mut [U], &mut U, &mut [U])
+    where
+        U: Ord,
+    {
+        assert!(k < self.len(), "partition index {} is out of range for slice of length {}", k, self.len());
+        self.select_nth_unstable(k)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn select_nth_by_dc<F: FnMut(&U, &U) -> Ordering>(
+        &mut self,
+        k: usize,
+        mut compare: F,
+    ) -> (&mut [U], &mut U, &mut [U]) {
+        assert!(k < self.len(), "partition index {} is out of range for slice of length {}", k, self.len());
+        for (i, element) in self.iter().enumerate() {
+            assert!(compare(element, element) == Ordering::Equal, "comparator doesn't consider the element at index {} equal to itself", i);
+        }
+        for (i, pair) in self.windows(2).enumerate() {
+            assert!(
+                compare(&pair[0], &pair[1]) == compare(&pair[1], &pair[0]).reverse(),
+                "comparator is not antisymmetric for the elements at indexes {} and {}",
+                i,
+                i + 1
+            );
+        }
+        self.select_nth_unstable_by(k, compare)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn fill_range_dc(&mut self, range: Range<usize>, value: U)
+    where
+        U: Clone,
+    {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "range {:?} is out of range for slice of length {}",
+            range,
+            self.len()
+        );
+        self[range].fill(value);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn reset_range_dc(&mut self, range: Range<usize>)
+    where
+        U: Default,
+    {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "range {:?} is out of range for slice of length {}",
+            range,
+            self.len()
+        );
+        self[range].fill_with(U::default);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn assert_partitioned_dc<P: FnMut(&U) -> bool>(&self, p: usize, mut pred: P) {
+        assert!(p <= self.len(), "partition point {} is out of range for slice of length {}", p, self.len());
+        for (i, element) in self.iter().enumerate() {
+            if i < p {
+                assert!(pred(element), "element at index {} doesn't satisfy the predicate but it's before the partition point {}", i, p);
+            } else {
+                assert!(!pred(element), "element at index {} satisfies the predicate but it's not before the partition point {}", i, p);
+            }
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn rotate_left_dc(&mut self, mid: usize) {
+        assert!(mid <= self.len(), "rotation amount {} is out of range for slice of length {}", mid, self.len());
+        #[cfg(feature = "heavy_checks")]
+        let before = U::sorted_copy(self);
+        self.rotate_left(mid);
+        #[cfg(feature = "heavy_checks")]
+        assert!(
+            before.is_none_or(|before| U::same_elements(&before, self)),
+            "left rotation by {} of slice of length {} didn't preserve its elements",
+            mid,
+            self.len()
+        );
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn rotate_right_dc(&mut self, k: usize) {
+        assert!(k <= self.len(), "rotation amount {} is out of range for slice of length {}", k, self.len());
+        #[cfg(feature = "heavy_checks")]
+        let before = U::sorted_copy(self);
+        self.rotate_right(k);
+        #[cfg(feature = "heavy_checks")]
+        assert!(
+            before.is_none_or(|before| U::same_elements(&before, self)),
+            "right rotation by {} of slice of length {} didn't preserve its elements",
+            k,
+            self.len()
+        );
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_by_dc<I: DcIndex>(&self, index: I) -> &U {
+        &self[index.into()]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_by_dc_mut<I: DcIndex>(&mut self, index: I) -> &mut U {
+        &mut self[index.into()]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_with_dc<F: FnOnce() -> usize>(&self, f: F) -> &U {
+        &self[f()]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_with_dc_mut<F: FnOnce() -> usize>(&mut self, f: F) -> &mut U {
+        &mut self[f()]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn split_at_raw_dc(&mut self, mid: usize) -> (*mut [U], *mut [U]) {
+        assert!(mid <= self.len(), "mid {} is out of range for slice of length {}", mid, self.len());
+        let len = self.len();
+        let ptr = self.as_mut_ptr();
+        (ptr::slice_from_raw_parts_mut(ptr, mid), ptr::slice_from_raw_parts_mut(ptr.add(mid), len - mid))
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn submatrix_dc(&self, rows: Range<usize>, cols: Range<usize>, width: usize) -> impl Iterator<Item = &[U]> {
+        assert!(cols.start <= cols.end, "column range {:?} starts after it ends", cols);
+        assert!(cols.end <= width, "column range {:?} is out of range for width {}", cols, width);
+        assert!(rows.start <= rows.end, "row range {:?} starts after it ends", rows);
+        assert!(
+            rows.end.checked_mul(width).is_some_and(|end| end <= self.len()),
+            "row range {:?} is out of range for slice of length {} with width {}",
+            rows,
+            self.len(),
+            width
+        );
+        let Range { start, end } = cols;
+        rows.map(move |row| &self[row * width + start..row * width + end])
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn apply_permutation_dc(&mut self, perm: &[usize]) {
+        assert!(perm.len() == self.len(), "permutation length ({}) does not match slice length ({})", perm.len(), self.len());
+        let mut seen = vec![false; perm.len()];
+        for (position, &index) in perm.iter().enumerate() {
+            assert!(index < perm.len(), "index {} (at position {}) is out of range for slice of length {}", index, position, perm.len());
+            assert!(!seen[index], "index {} (at position {}) is repeated in the permutation", index, position);
+            seen[index] = true;
+        }
+        let mut visited = seen;
+        visited.fill(false);
+        for start in 0..perm.len() {
+            if visited[start] {
+                continue;
+            }
+            let mut current = start;
+            loop {
+                visited[current] = true;
+                let next = perm[current];
+                if next == start {
+                    break;
+                }
+                self.swap(current, next);
+                current = next;
+            }
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn chunks_count_dc(&self, n: usize, expected_chunks: usize) -> impl Iterator<Item = &[U]> {
+        assert!(n != 0, "chunk size must be non-zero");
+        assert!(
+            n.checked_mul(expected_chunks) == Some(self.len()),
+            "slice of length {} is not {} chunks of size {}",
+            self.len(),
+            expected_chunks,
+            n
+        );
+        self.chunks_exact(n)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn assume_len_dc(&self, min_len: usize) {
+        assert!(self.len() >= min_len, "slice of length {} is shorter than the assumed length {}", self.len(), min_len);
+    }
+
+    #[inline]
+    fn as_ptr_range_dc(&self) -> Range<*const U> {
+        self.as_ptr_range()
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn ptr_at_dc(&self, index: usize) -> *const U {
+        assert!(index <= self.len(), "index {} is out of range for slice of length {}", index, self.len());
+        self.as_ptr().add(index)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn enumerate_from_dc(&self, start: usize, count: usize) -> impl Iterator<Item = (usize, &U)> {
+        assert!(
+            start.checked_add(count).is_some_and(|end| end <= self.len()),
+            "range of {} elements starting at {} is out of range for slice of length {}",
+            count,
+            start,
+            self.len()
+        );
+        self[start..start + count].iter().enumerate().map(move |(k, element)| (start + k, element))
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn binary_search_by_key_dc<K: Ord, F: FnMut(&U) -> K>(&self, key: &K, mut f: F) -> Result<usize, usize> {
+        let mut keys = self.iter().map(&mut f);
+        if let Some(mut previous) = keys.next() {
+            for (i, current) in keys.enumerate() {
+                assert!(
+                    previous <= current,
+                    "slice is not sorted by key: key of element at index {} is greater than key of element at index {}",
+                    i,
+                    i + 1
+                );
+                previous = current;
+            }
+        }
+        self.binary_search_by_key(key, f)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_strided_dc(&self, index: usize, base: usize, stride: usize) -> &U {
+        &self[strided_offset(self.len(), index, base, stride)]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_strided_dc_mut(&mut self, index: usize, base: usize, stride: usize) -> &mut U {
+        let offset = strided_offset(self.len(), index, base, stride);
+        &mut self[offset]
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<U> DcSliceElements for [U] {
+    type Element = U;
+
+    #[inline(always)]
+    unsafe fn as_array_dc<const N: usize>(&self) -> &[U; N] {
+        &*(self.as_ptr() as *const [U; N])
+    }
+
+    #[inline(always)]
+    unsafe fn as_array_dc_mut<const N: usize>(&mut self) -> &mut [U; N] {
+        &mut *(self.as_mut_ptr() as *mut [U; N])
+    }
+
+    #[inline(always)]
+    unsafe fn split_first_chunk_dc<const N: usize>(&self) -> (&[U; N], &[U]) {
+        (&*(self.as_ptr() as *const [U; N]), self.get_unchecked(N..))
+    }
+
+    #[inline(always)]
+    unsafe fn split_first_chunk_dc_mut<const N: usize>(&mut self) -> (&mut [U; N], &mut [U]) {
+        let (head, tail) = self.split_at_mut_unchecked(N);
+        (&mut *(head.as_mut_ptr() as *mut [U; N]), tail)
+    }
+
+    #[inline(always)]
+    unsafe fn get_from_end_dc(&self, k: usize) -> &U {
+        self.get_unchecked(self.len() - 1 - k)
+    }
+
+    #[inline(always)]
+    unsafe fn get_from_end_dc_mut(&mut self, k: usize) -> &mut U {
+        let len = self.len();
+        self.get_unchecked_mut(len - 1 - k)
+    }
+
+    #[inline(always)]
+    unsafe fn clone_into_dc(&self, dst: &mut [U])
+    where
+        U: Clone,
+    {
+        for i in 0..self.len() {
+            dst.get_unchecked_mut(i).clone_from(self.get_unchecked(i));
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn clone_from_slice_dc(&mut self, src: &[U])
+    where
+        U: Clone,
+    {
+        for i in 0..self.len() {
+            self.get_unchecked_mut(i).clone_from(src.get_unchecked(i));
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn take_exact_dc(&self, n: usize) -> impl Iterator<Item = &U> {
+        self.get_unchecked(..n).iter()
+    }
+
+    #[inline(always)]
+    unsafe fn sort_by_cached_key_dc<K: Ord, F: FnMut(&U) -> K>(&mut self, f: F) {
+        self.sort_by_cached_key(f);
+    }
+
+    #[inline(always)]
+    unsafe fn array_windows_dc<const N: usize>(&self) -> impl Iterator<Item = &[U; N]> {
+        let ptr = self.as_ptr();
+        (0..=self.len() - N).map(move |i| &*(ptr.add(i) as *const [U; N]))
+    }
+
+    #[inline(always)]
+    unsafe fn as_chunks_array_dc<const N: usize>(&self) -> &[[U; N]] {
+        self.as_chunks_unchecked()
+    }
+
+    #[inline(always)]
+    unsafe fn as_chunks_array_dc_mut<const N: usize>(&mut self) -> &mut [[U; N]] {
+        self.as_chunks_unchecked_mut()
+    }
+
+    #[inline(always)]
+    unsafe fn gather_dc<const N: usize>(&self, indices: [usize; N]) -> [&U; N] {
+        indices.map(|index| self.get_unchecked(index))
+    }
+
+    #[inline(always)]
+    unsafe fn find_dc(&self, x: &U) -> usize
+    where
+        U: PartialEq,
+    {
+        for (i, element) in self.iter().enumerate() {
+            if element == x {
+                return i;
+            }
+        }
+        unreachable_dc()
+    }
+
+    #[inline(always)]
+    unsafe fn split_inclusive_dc<P: FnMut(&U) -> bool>(&self, pred: P) -> impl Iterator<Item = &[U]> {
+        self.split_inclusive(pred)
+    }
+
+    #[inline(always)]
+    unsafe fn copy_from_slice_dc(&mut self, src: &[U])
+    where
+        U: Copy,
+    {
+        ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr(), self.len());
+    }
+
+    #[inline(always)]
+    unsafe fn copy_within_dc(&mut self, src: Range<usize>, dest: usize)
+    where
+        U: Copy,
+    {
+        let ptr = self.as_mut_ptr();
+        ptr::copy(ptr.add(src.start), ptr.add(dest), src.end.unchecked_sub(src.start));
+    }
+
+    #[inline(always)]
+    unsafe fn copy_within_nonoverlapping_dc(&mut self, src: Range<usize>, dest: usize)
+    where
+        U: Copy,
+    {
+        let ptr = self.as_mut_ptr();
+        ptr::copy_nonoverlapping(ptr.add(src.start), ptr.add(dest), src.end.unchecked_sub(src.start));
+    }
+
+    #[inline(always)]
+    unsafe fn fill_from_iter_dc<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        for slot in self.iter_mut() {
+            *slot = iter.next().unwrap_unchecked();
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn chunks_exact_with_remainder_dc(&self, n: usize) -> (impl Iterator<Item = &[U]>, &[U]) {
+        hint::assert_unchecked(n != 0);
+        let chunks = self.chunks_exact(n);
+        let remainder = chunks.remainder();
+        (chunks, remainder)
+    }
+
+    #[inline(always)]
+    unsafe fn rchunks_exact_dc(&self, n: usize) -> impl Iterator<Item = &[U]> {
+        hint::assert_unchecked(n != 0);
+        self.rchunks_exact(n)
+    }
+
+    #[inline(always)]
+    unsafe fn rchunks_array_dc<const N: usize>(&self) -> impl Iterator<Item = &[U; N]> {
+        self.as_chunks_unchecked::<N>().iter().rev()
+    }
+
+    #[inline(always)]
+    fn cursor_dc(&self) -> DcCursor<'_, U> {
+        DcCursor::new(self)
+    }
+
+    #[inline(always)]
+    unsafe fn select_nth_dc(&mut self, k: usize) -> (&mut [U], &mut U, &mut [U])
+    where
+        U: Ord,
+    {
+        hint::assert_unchecked(k < self.len());
+        self.select_nth_unstable(k)
+    }
+
+    #[inline(always)]
+    unsafe fn select_nth_by_dc<F: FnMut(&U, &U) -> Ordering>(
+        &mut self,
+        k: usize,
+        compare: F,
+    ) -> (&mut [U], &mut U, &mut [U]) {
+        hint::assert_unchecked(k < self.len());
+        self.select_nth_unstable_by(k, compare)
+    }
+
+    #[inline(always)]
+    unsafe fn fill_range_dc(&mut self, range: Range<usize>, value: U)
+    where
+        U: Clone,
+    {
+        self.get_unchecked_mut(range).fill(value);
+    }
+
+    #[inline(always)]
+    unsafe fn reset_range_dc(&mut self, range: Range<usize>)
+    where
+        U: Default,
+    {
+        self.get_unchecked_mut(range).fill_with(U::default);
+    }
+
+    #[inline(always)]
+    unsafe fn assert_partitioned_dc<P: FnMut(&U) -> bool>(&self, _p: usize, _pred: P) {}
+
+    #[inline(always)]
+    unsafe fn rotate_left_dc(&mut self, mid: usize) {
+        hint::assert_unchecked(mid <= self.len());
+        self.rotate_left(mid);
+    }
+
+    #[inline(always)]
+    unsafe fn rotate_right_dc(&mut self, k: usize) {
+        hint::assert_unchecked(k <= self.len());
+        self.rotate_right(k);
+    }
+
+    #[inline(always)]
+    unsafe fn get_by_dc<I: DcIndex>(&self, index: I) -> &U {
+        self.get_unchecked(index.into())
+    }
+
+    #[inline(always)]
+    unsafe fn get_by_dc_mut<I: DcIndex>(&mut self, index: I) -> &mut U {
+        self.get_unchecked_mut(index.into())
+    }
+
+    #[inline(always)]
+    unsafe fn get_with_dc<F: FnOnce() -> usize>(&self, f: F) -> &U {
+        self.get_unchecked(f())
+    }
+
+    #[inline(always)]
+    unsafe fn get_with_dc_mut<F: FnOnce() -> usize>(&mut self, f: F) -> &mut U {
+        self.get_unchecked_mut(f())
+    }
+
+    #[inline(always)]
+    unsafe fn split_at_raw_dc(&mut self, mid: usize) -> (*mut [U], *mut [U]) {
+        let len = self.len();
+        let ptr = self.as_mut_ptr();
+        (ptr::slice_from_raw_parts_mut(ptr, mid), ptr::slice_from_raw_parts_mut(ptr.add(mid), len.unchecked_sub(mid)))
+    }
+
+    #[inline(always)]
+    unsafe fn submatrix_dc(&self, rows: Range<usize>, cols: Range<usize>, width: usize) -> impl Iterator<Item = &[U]> {
+        let Range { start, end } = cols;
+        rows.map(move |row| self.get_unchecked(row * width + start..row * width + end))
+    }
+
+    #[inline(always)]
+    unsafe fn apply_permutation_dc(&mut self, perm: &[usize]) {
+        let ptr = self.as_mut_ptr();
+        let mut visited = vec![false; self.len()];
+        for start in 0..self.len() {
+            if *visited.get_unchecked(start) {
+                continue;
+            }
+            let mut current = start;
+            loop {
+                *visited.get_unchecked_mut(current) = true;
+                let next = *perm.get_unchecked(current);
+                if next == start {
+                    break;
+                }
+                ptr::swap_nonoverlapping(ptr.add(current), ptr.add(next), 1);
+                current = next;
+            }
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn chunks_count_dc(&self, n: usize, _expected_chunks: usize) -> impl Iterator<Item = &[U]> {
+        hint::assert_unchecked(n != 0);
+        self.chunks_exact(n)
+    }
+
+    #[inline(always)]
+    unsafe fn assume_len_dc(&self, min_len: usize) {
+        hint::assert_unchecked(self.len() >= min_len);
+    }
+
+    #[inline(always)]
+    fn as_ptr_range_dc(&self) -> Range<*const U> {
+        self.as_ptr_range()
+    }
+
+    #[inline(always)]
+    unsafe fn ptr_at_dc(&self, index: usize) -> *const U {
+        self.as_ptr().add(index)
+    }
+
+    #[inline(always)]
+    unsafe fn enumerate_from_dc(&self, start: usize, count: usize) -> impl Iterator<Item = (usize, &U)> {
+        self.get_unchecked(start..start.unchecked_add(count)).iter().enumerate().map(move |(k, element)| (start.unchecked_add(k), element))
+    }
+
+    #[inline(always)]
+    unsafe fn binary_search_by_key_dc<K: Ord, F: FnMut(&U) -> K>(&self, key: &K, f: F) -> Result<usize, usize> {
+        self.binary_search_by_key(key, f)
+    }
+
+    #[inline(always)]
+    unsafe fn get_strided_dc(&self, index: usize, base: usize, stride: usize) -> &U {
+        self.get_unchecked(base.unchecked_add(index.unchecked_mul(stride)))
+    }
+
+    #[inline(always)]
+    unsafe fn get_strided_dc_mut(&mut self, index: usize, base: usize, stride: usize) -> &mut U {
+        self.get_unchecked_mut(base.unchecked_add(index.unchecked_mul(stride)))
+    }
+}
+
+/// Computes `base + index * stride`, checking that it doesn't overflow and that it's in range for a slice of length `len`.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+fn strided_offset(len: usize, index: usize, base: usize, stride: usize) -> usize {
+    let offset = index.checked_mul(stride).and_then(|step| step.checked_add(base));
+    match offset {
+        Some(offset) if offset < len => offset,
+        Some(offset) => panic!(
+            "strided offset {} (base {} + index {} * stride {}) is out of range for slice of length {}",
+            offset, base, index, stride, len
+        ),
+        None => panic!("strided offset overflows (base {} + index {} * stride {})", base, index, stride),
+    }
+}
+
+/// Asserts that `slice` is sorted in ascending order.
+///
+/// This is safe, as it doesn't let the compiler assume anything: it only checks on Debug and it's a no-op on Release.
+///
+/// # Panics (Debug)
+///
+/// Panics at the first pair of adjacent elements which are out of order, showing their indexes and values.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+pub fn debug_assert_sorted<U: Ord + Debug>(slice: &[U]) {
+    debug_assert_sorted_by(slice, U::cmp);
+}
+
+/// Asserts that `slice` is sorted in ascending order.
+///
+/// This is safe, as it doesn't let the compiler assume anything: it only checks on Debug and it's a no-op on Release.
+///
+/// # Panics (Debug)
+///
+/// Panics at the first pair of adjacent elements which are out of order, showing their indexes and values.
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub fn debug_assert_sorted<U: Ord + Debug>(_slice: &[U]) {}
+
+/// Asserts that `slice` is sorted in ascending order according to the comparator function.
+///
+/// This is safe, as it doesn't let the compiler assume anything: it only checks on Debug and it's a no-op on Release.
+///
+/// # Panics (Debug)
+///
+/// Panics at the first pair of adjacent elements which are out of order, showing their indexes and values.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+pub fn debug_assert_sorted_by<U: Debug, F: FnMut(&U, &U) -> Ordering>(slice: &[U], mut compare: F) {
+    for (i, pair) in slice.windows(2).enumerate() {
+        assert!(
+            compare(&pair[0], &pair[1]) != Ordering::Greater,
+            "slice is not sorted: element at index {} ({:?}) is greater than element at index {} ({:?})",
+            i,
+            pair[0],
+            i + 1,
+            pair[1]
+        );
+    }
+}
+
+/// Asserts that `slice` is sorted in ascending order according to the comparator function.
+///
+/// This is safe, as it doesn't let the compiler assume anything: it only checks on Debug and it's a no-op on Release.
+///
+/// # Panics (Debug)
+///
+/// Panics at the first pair of adjacent elements which are out of order, showing their indexes and values.
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub fn debug_assert_sorted_by<U: Debug, F: FnMut(&U, &U) -> Ordering>(_slice: &[U], _compare: F) {}
+
+/// Asserts that all the elements of `slice` satisfy `pred`.
+///
+/// This is safe, as it doesn't let the compiler assume anything: it only checks on Debug and it's a no-op on Release.
+///
+/// # Panics (Debug)
+///
+/// Panics at the first element which doesn't satisfy `pred`, showing its index.
+#[cfg(debug_assertions)]
+#[inline]
+#[track_caller]
+pub fn debug_assert_all<U, P: FnMut(&U) -> bool>(slice: &[U], mut pred: P) {
+    for (i, element) in slice.iter().enumerate() {
+        assert!(pred(element), "element at index {} of slice of length {} doesn't satisfy the predicate", i, slice.len());
+    }
+}
+
+/// Asserts that all the elements of `slice` satisfy `pred`.
+///
+/// This is safe, as it doesn't let the compiler assume anything: it only checks on Debug and it's a no-op on Release.
+///
+/// # Panics (Debug)
+///
+/// Panics at the first element which doesn't satisfy `pred`, showing its index.
+#[cfg(not(debug_assertions))]
+#[inline(always)]
+pub fn debug_assert_all<U, P: FnMut(&U) -> bool>(_slice: &[U], _pred: P) {}
+
+/// Iterator returned by `DcSliceElements::take_exact_dc` on Debug.
+///
+/// It panics on drop if it wasn't fully consumed.
+#[cfg(debug_assertions)]
+struct TakeExact<'a, U> {
+    iter: Iter<'a, U>,
+    location: &'static Location<'static>,
+}
+
+#[cfg(debug_assertions)]
+impl<'a, U> Iterator for TakeExact<'a, U> {
+    type Item = &'a U;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<U> Drop for TakeExact<'_, U> {
+    fn drop(&mut self) {
+        if !std::thread::panicking() && self.iter.len() != 0 {
+            panic!(
+                "iterator returned by `take_exact_dc` at {} was dropped with {} elements not consumed",
+                self.location,
+                self.iter.len()
+            );
+        }
+    }
 }
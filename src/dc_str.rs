@@ -0,0 +1,110 @@
+//! Implement extension methods over `str`.
+
+use std::slice::SliceIndex;
+
+/// Defines methods for getting subslices of a string at specified byte ranges without performing check on Release, but panicking on Debug.
+pub trait DcStr {
+    /// Get the subslice at the specified byte range without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the range is out of bounds or any of its bounds is not on a char boundary, with the same message as indexing the string
+    /// (naming the byte index and the character that contains it).
+    ///
+    /// # Safety
+    ///
+    /// Range should always be in bounds and on char boundaries.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_dc<T: SliceIndex<str>>(&self, index: T) -> &T::Output;
+
+    /// Get the subslice at the specified byte range without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if the range is out of bounds or any of its bounds is not on a char boundary.
+    ///
+    /// # Safety
+    ///
+    /// Range should always be in bounds and on char boundaries.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_expect_dc<T: SliceIndex<str>>(&self, index: T, msg: &str) -> &T::Output;
+
+    /// Get the mutable subslice at the specified byte range without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the range is out of bounds or any of its bounds is not on a char boundary, with the same message as indexing the string
+    /// (naming the byte index and the character that contains it).
+    ///
+    /// # Safety
+    ///
+    /// Range should always be in bounds and on char boundaries.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_dc_mut<T: SliceIndex<str>>(&mut self, index: T) -> &mut T::Output;
+
+    /// Get the mutable subslice at the specified byte range without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if the range is out of bounds or any of its bounds is not on a char boundary.
+    ///
+    /// # Safety
+    ///
+    /// Range should always be in bounds and on char boundaries.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_expect_dc_mut<T: SliceIndex<str>>(&mut self, index: T, msg: &str) -> &mut T::Output;
+}
+
+#[cfg(debug_assertions)]
+impl DcStr for str {
+    #[inline]
+    #[track_caller]
+    unsafe fn get_dc<T: SliceIndex<str>>(&self, index: T) -> &T::Output {
+        &self[index]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_expect_dc<T: SliceIndex<str>>(&self, index: T, msg: &str) -> &T::Output {
+        self.get(index).expect(msg)
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_dc_mut<T: SliceIndex<str>>(&mut self, index: T) -> &mut T::Output {
+        &mut self[index]
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn get_expect_dc_mut<T: SliceIndex<str>>(&mut self, index: T, msg: &str) -> &mut T::Output {
+        self.get_mut(index).expect(msg)
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl DcStr for str {
+    #[inline(always)]
+    unsafe fn get_dc<T: SliceIndex<str>>(&self, index: T) -> &T::Output {
+        self.get_unchecked(index)
+    }
+
+    #[inline(always)]
+    unsafe fn get_expect_dc<T: SliceIndex<str>>(&self, index: T, _msg: &str) -> &T::Output {
+        self.get_unchecked(index)
+    }
+
+    #[inline(always)]
+    unsafe fn get_dc_mut<T: SliceIndex<str>>(&mut self, index: T) -> &mut T::Output {
+        self.get_unchecked_mut(index)
+    }
+
+    #[inline(always)]
+    unsafe fn get_expect_dc_mut<T: SliceIndex<str>>(&mut self, index: T, _msg: &str) -> &mut T::Output {
+        self.get_unchecked_mut(index)
+    }
+}
@@ -0,0 +1,243 @@
+//! Implement extension methods over `Vec<T>`.
+
+use std::{mem::MaybeUninit, slice};
+
+#[cfg(not(debug_assertions))]
+use std::ptr;
+
+/// Defines methods for manipulating a `Vec<T>` without performing checks on Release, but panicking on Debug.
+pub trait DcVec {
+    /// Type of the elements in the `Vec<T>`.
+    type Element;
+
+    /// Returns the initialized part of the vector together with its spare capacity without performing checks on release.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the length of the vector is greater than its capacity.
+    ///
+    /// # Safety
+    ///
+    /// Length of the vector must not be greater than its capacity (which can only happen after a prior misuse of `set_len`).
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn split_at_spare_dc(&mut self) -> (&mut [Self::Element], &mut [MaybeUninit<Self::Element>]);
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right, without performing checks on release.
+    ///
+    /// The vector must have spare capacity for the new element, as it is never reallocated.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `index` is greater than the length of the vector, or if the length of the vector is equal to its capacity.
+    ///
+    /// # Safety
+    ///
+    /// `index` must not be greater than the length of the vector, and the vector must have spare capacity for at least one element.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn insert_dc(&mut self, index: usize, element: Self::Element);
+
+    /// Appends clones of all the elements of `slices` to the vector without performing checks on release.
+    ///
+    /// `total` is the sum of the lengths of `slices`, and the vector must have spare capacity for it, as it is never reallocated.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the sum of the lengths of `slices` is not `total`, or if the vector doesn't have spare capacity for `total` elements.
+    ///
+    /// # Safety
+    ///
+    /// The sum of the lengths of `slices` must be `total`, and the vector must have spare capacity for at least `total` elements.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn concat_into_dc(&mut self, slices: &[&[Self::Element]], total: usize)
+    where
+        Self::Element: Clone;
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `len` is greater than the length of the vector.
+    ///
+    /// # Safety
+    ///
+    /// `len` must not be greater than the length of the vector.
+    ///
+    /// Failing this makes the call a no-op on Release.
+    unsafe fn truncate_dc(&mut self, len: usize);
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if the predicate returns different results when called twice on the same element.
+    ///
+    /// # Safety
+    ///
+    /// The predicate must be a pure function of each element.
+    ///
+    /// Failing this may retain unexpected elements on Release.
+    unsafe fn retain_dc<F: FnMut(&Self::Element) -> bool>(&mut self, pred: F);
+
+    /// Removes all but the first of consecutive elements that resolve to the same key, for a vector grouped by key.
+    ///
+    /// As keys are only `PartialEq`, on Debug each new key is only compared with the key of the group before the previous one,
+    /// so a key reappearing after several different groups is not detected.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if a key reappears right after a different group, showing the indexes where both groups start.
+    ///
+    /// # Safety
+    ///
+    /// Elements with equal keys must be contiguous.
+    ///
+    /// Failing this keeps elements with duplicated keys on Release.
+    unsafe fn dedup_by_key_sorted_dc<K: PartialEq, F: FnMut(&mut Self::Element) -> K>(&mut self, key: F);
+}
+
+#[cfg(debug_assertions)]
+impl<T> DcVec for Vec<T> {
+    type Element = T;
+
+    #[inline]
+    #[track_caller]
+    unsafe fn split_at_spare_dc(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let len = self.len();
+        let capacity = self.capacity();
+        assert!(len <= capacity, "length of vector ({}) is greater than its capacity ({})", len, capacity);
+        let ptr = self.as_mut_ptr();
+        (
+            slice::from_raw_parts_mut(ptr, len),
+            slice::from_raw_parts_mut(ptr.add(len) as *mut MaybeUninit<T>, capacity - len),
+        )
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn insert_dc(&mut self, index: usize, element: T) {
+        let len = self.len();
+        assert!(index <= len, "insertion index (is {}) should be <= len (is {})", index, len);
+        assert!(len < self.capacity(), "vector has no spare capacity (length and capacity are {})", len);
+        self.insert(index, element);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn concat_into_dc(&mut self, slices: &[&[T]], total: usize)
+    where
+        T: Clone,
+    {
+        let sum: usize = slices.iter().map(|slice| slice.len()).sum();
+        assert!(sum == total, "sum of the lengths of the slices ({}) is not the expected total ({})", sum, total);
+        let spare = self.capacity() - self.len();
+        assert!(spare >= total, "vector has spare capacity for {} elements but {} are required", spare, total);
+        for slice in slices {
+            self.extend_from_slice(slice);
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn truncate_dc(&mut self, len: usize) {
+        assert!(len <= self.len(), "truncation length (is {}) should be <= len (is {})", len, self.len());
+        self.truncate(len);
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn retain_dc<F: FnMut(&T) -> bool>(&mut self, mut pred: F) {
+        let mut keep = Vec::with_capacity(self.len());
+        for (i, element) in self.iter().enumerate() {
+            let result = pred(element);
+            assert!(result == pred(element), "predicate returned different results for the element at index {}", i);
+            keep.push(result);
+        }
+        // `retain` visits each element exactly once in the original order.
+        let mut keep = keep.into_iter();
+        self.retain(|_| keep.next().unwrap());
+    }
+
+    #[inline]
+    #[track_caller]
+    unsafe fn dedup_by_key_sorted_dc<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        // Index and key of the first element of the current and previous groups.
+        let mut current: Option<(usize, K)> = None;
+        let mut previous: Option<(usize, K)> = None;
+        for (i, element) in self.iter_mut().enumerate() {
+            let next = key(element);
+            if current.as_ref().is_some_and(|(_, current)| *current == next) {
+                continue;
+            }
+            if let (Some((previous_index, previous)), Some((current_index, _))) = (&previous, &current) {
+                assert!(
+                    *previous != next,
+                    "vector is not grouped by key: key of element at index {} equals key of element at index {}, but a different key appeared at index {}",
+                    i,
+                    previous_index,
+                    current_index
+                );
+            }
+            previous = current.replace((i, next));
+        }
+        self.dedup_by_key(key);
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl<T> DcVec for Vec<T> {
+    type Element = T;
+
+    #[inline(always)]
+    unsafe fn split_at_spare_dc(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let len = self.len();
+        let capacity = self.capacity();
+        let ptr = self.as_mut_ptr();
+        (
+            slice::from_raw_parts_mut(ptr, len),
+            slice::from_raw_parts_mut(ptr.add(len) as *mut MaybeUninit<T>, capacity - len),
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn insert_dc(&mut self, index: usize, element: T) {
+        let len = self.len();
+        let ptr = self.as_mut_ptr().add(index);
+        ptr::copy(ptr, ptr.add(1), len - index);
+        ptr::write(ptr, element);
+        self.set_len(len + 1);
+    }
+
+    #[inline(always)]
+    unsafe fn concat_into_dc(&mut self, slices: &[&[T]], _total: usize)
+    where
+        T: Clone,
+    {
+        let ptr = self.as_mut_ptr();
+        let mut len = self.len();
+        for slice in slices {
+            for element in slice.iter() {
+                ptr.add(len).write(element.clone());
+                len += 1;
+                self.set_len(len);
+            }
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn truncate_dc(&mut self, len: usize) {
+        self.truncate(len);
+    }
+
+    #[inline(always)]
+    unsafe fn retain_dc<F: FnMut(&T) -> bool>(&mut self, pred: F) {
+        self.retain(pred);
+    }
+
+    #[inline(always)]
+    unsafe fn dedup_by_key_sorted_dc<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, key: F) {
+        self.dedup_by_key(key);
+    }
+}
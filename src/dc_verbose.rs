@@ -0,0 +1,177 @@
+//! Implement aliases with descriptive names for the most common methods, enabled with the `verbose_names` feature.
+
+use std::slice::SliceIndex;
+
+use crate::{DcOption, DcResultErr, DcResultOk, DcSlice};
+
+/// Descriptive aliases for the methods of `DcOption`.
+pub trait DcOptionVerbose: DcOption {
+    /// Alias of `DcOption::unwrap_dc`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_checked_in_debug(self) -> Self::Result;
+
+    /// Alias of `DcOption::expect_dc`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `None`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Some(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_checked_in_debug(self, msg: &str) -> Self::Result;
+}
+
+impl<O: DcOption> DcOptionVerbose for O {
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    unsafe fn unwrap_checked_in_debug(self) -> Self::Result {
+        self.unwrap_dc()
+    }
+
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    unsafe fn expect_checked_in_debug(self, msg: &str) -> Self::Result {
+        self.expect_dc(msg)
+    }
+}
+
+/// Descriptive aliases for the methods of `DcResultOk`.
+pub trait DcResultOkVerbose: DcResultOk {
+    /// Alias of `DcResultOk::unwrap_dc_ok`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_ok_checked_in_debug(self) -> Self::T;
+
+    /// Alias of `DcResultOk::expect_dc_ok`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Err(E)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Ok(T)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_ok_checked_in_debug(self, msg: &str) -> Self::T;
+}
+
+impl<R: DcResultOk> DcResultOkVerbose for R {
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    unsafe fn unwrap_ok_checked_in_debug(self) -> Self::T {
+        self.unwrap_dc_ok()
+    }
+
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    unsafe fn expect_ok_checked_in_debug(self, msg: &str) -> Self::T {
+        self.expect_dc_ok(msg)
+    }
+}
+
+/// Descriptive aliases for the methods of `DcResultErr`.
+pub trait DcResultErrVerbose: DcResultErr {
+    /// Alias of `DcResultErr::unwrap_dc_err`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if `self` is `Ok(T)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Err(E)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn unwrap_err_checked_in_debug(self) -> Self::E;
+
+    /// Alias of `DcResultErr::expect_dc_err`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics with the specified message if `self` is `Ok(T)`.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be `Err(E)`.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn expect_err_checked_in_debug(self, msg: &str) -> Self::E;
+}
+
+impl<R: DcResultErr> DcResultErrVerbose for R {
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    unsafe fn unwrap_err_checked_in_debug(self) -> Self::E {
+        self.unwrap_dc_err()
+    }
+
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    unsafe fn expect_err_checked_in_debug(self, msg: &str) -> Self::E {
+        self.expect_dc_err(msg)
+    }
+}
+
+/// Descriptive aliases for the methods of `DcSlice`.
+pub trait DcSliceVerbose: DcSlice {
+    /// Alias of `DcSlice::get_dc`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if index is out of range.
+    ///
+    /// # Safety
+    ///
+    /// Index should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_checked_in_debug<T: SliceIndex<Self>>(&self, index: T) -> &T::Output;
+
+    /// Alias of `DcSlice::get_dc_mut`.
+    ///
+    /// # Panics (Debug)
+    ///
+    /// Panics if index is out of range.
+    ///
+    /// # Safety
+    ///
+    /// Index should always be on range.
+    ///
+    /// Failing this produces undefined behavior on Release.
+    unsafe fn get_mut_checked_in_debug<T: SliceIndex<Self>>(&mut self, index: T) -> &mut T::Output;
+}
+
+impl<S: DcSlice + ?Sized> DcSliceVerbose for S {
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    unsafe fn get_checked_in_debug<T: SliceIndex<Self>>(&self, index: T) -> &T::Output {
+        self.get_dc(index)
+    }
+
+    #[inline(always)]
+    #[cfg_attr(debug_assertions, track_caller)]
+    unsafe fn get_mut_checked_in_debug<T: SliceIndex<Self>>(&mut self, index: T) -> &mut T::Output {
+        self.get_dc_mut(index)
+    }
+}
@@ -9,21 +9,64 @@
 //! For the purposes of the documentation of the crate, "Debug" means having the compiler flag `debug_assertions` and "Release" means not having it.
 //!
 //! That is, if you compile "Release" using `debug_assertions`, you could use it to check for errors in your release.
+//!
+//! The unchecked implementations used on Release are marked `#[inline(always)]`, so they are as cheap as their `_unchecked` counterparts.
+//! For example, on Release `get_dc`, `unwrap_dc` and `unwrap_dc_ok` generate the same machine code as `get_unchecked` and `unwrap_unchecked`.
+//! The implementations used on Debug, and the methods shared by both profiles which perform checks on Debug, are only marked `#[inline]`,
+//! as forcing the inlining of the checks and their panic paths into every call site slows down Debug builds of large projects without providing any benefit to them.
+//! Shared methods which only forward to other methods of the crate keep `#[inline(always)]`, as the checks are in the methods they call.
+//!
+//! The implementations used on Debug which can panic (such as the methods of `DcOption`, `DcResultOk`, `DcResultErr`, `DcSlice` and `DcRefCell`) are marked `#[track_caller]`,
+//! so their panics are reported at the location of the call in your code instead of inside this crate.
+//! Methods which can't panic on Debug, such as `replace_with_dc` which aborts instead, aren't marked.
+//!
+//! With the `verbose_names` feature, the traits `DcOptionVerbose`, `DcResultOkVerbose`, `DcResultErrVerbose` and `DcSliceVerbose`
+//! provide aliases with descriptive names (such as `unwrap_checked_in_debug`) for the most common methods.
+//!
+//! The `_or` methods (`unwrap_dc_or`, `unwrap_dc_ok_or` and `get_dc_or`) take a fallback which is ignored on Debug and by default on Release.
+//! With the `soft_release` feature, their Release implementations still perform the check,
+//! but instead of producing undefined behavior they print a warning with the caller location to the standard error and return the fallback.
+//! This is intended as a safety net while gaining confidence in the invariants, before switching to the unchecked Release.
 
 #![feature(coerce_unsized)]
 #![feature(negative_impls)]
 #![feature(must_not_suspend)]
+#![cfg_attr(debug_assertions, feature(ptr_metadata))]
 #![feature(unsize)]
+#![feature(const_trait_impl)]
+#![feature(const_default)]
+#![cfg_attr(all(debug_assertions, feature = "heavy_checks"), allow(incomplete_features))]
+#![cfg_attr(all(debug_assertions, feature = "heavy_checks"), feature(specialization))]
+#![cfg_attr(all(feature = "cell_leak", debug_assertions), feature(cell_leak))]
+#![cfg_attr(feature = "slice_index_methods", feature(slice_index_methods))]
 
 mod dc_ref_cell;
+mod dc_control_flow;
+mod dc_cursor;
 mod dc_option;
+mod dc_poll;
+mod dc_ptr;
 mod dc_result;
 mod dc_slice;
+mod dc_str;
+mod dc_vec;
+#[cfg(all(debug_assertions, feature = "heavy_checks"))]
+mod same_elements;
+#[cfg(feature = "verbose_names")]
+mod dc_verbose;
 
 pub use dc_ref_cell::*;
+pub use dc_control_flow::*;
+pub use dc_cursor::*;
 pub use dc_option::*;
+pub use dc_poll::*;
+pub use dc_ptr::*;
 pub use dc_result::*;
 pub use dc_slice::*;
+pub use dc_str::*;
+pub use dc_vec::*;
+#[cfg(feature = "verbose_names")]
+pub use dc_verbose::*;
 
 /// Replaces the value in `reference` with a new one produced in `closure`.
 ///
@@ -37,7 +80,7 @@ pub use dc_slice::*;
 ///
 /// Failing this produces undefined behavior on Release.
 #[cfg(debug_assertions)]
-#[inline(always)]
+#[inline]
 pub unsafe fn replace_with_dc<T>(reference: &mut T, closure: impl FnOnce(T) -> T) {
     use std::{panic, ptr};
 
@@ -61,7 +104,7 @@ pub unsafe fn replace_with_dc<T>(reference: &mut T, closure: impl FnOnce(T) -> T
 #[cfg(not(debug_assertions))]
 #[inline(always)]
 pub unsafe fn replace_with_dc<T>(reference: &mut T, closure: impl FnOnce(T) -> T) {
-    use std::{panic, ptr};
+    use std::ptr;
 
     let old_value = ptr::read(reference);
     let new_value = closure(old_value);
@@ -0,0 +1,40 @@
+//! Comparison of the elements of slices for the `heavy_checks` feature, which relies on specialization.
+//!
+//! It's only declared with `debug_assertions` and the `heavy_checks` feature, as the syntax of specialization can't be compiled without the nightly feature.
+
+/// Compares the elements of slices regardless of their order, for the `heavy_checks` of the rotations.
+///
+/// It's specialized for `Ord + Clone` elements, and checks nothing for any other element.
+pub(crate) trait SameElements: Sized {
+    /// Returns a sorted copy of `slice`, or `None` if the elements can't be compared.
+    fn sorted_copy(slice: &[Self]) -> Option<Vec<Self>>;
+
+    /// Returns whether `slice` has the same elements as the sorted copy `sorted`.
+    fn same_elements(sorted: &[Self], slice: &[Self]) -> bool;
+}
+
+impl<U> SameElements for U {
+    #[inline]
+    default fn sorted_copy(_slice: &[U]) -> Option<Vec<U>> {
+        None
+    }
+
+    #[inline]
+    default fn same_elements(_sorted: &[U], _slice: &[U]) -> bool {
+        true
+    }
+}
+
+impl<U: Ord + Clone> SameElements for U {
+    #[inline]
+    fn sorted_copy(slice: &[U]) -> Option<Vec<U>> {
+        let mut copy = slice.to_vec();
+        copy.sort();
+        Some(copy)
+    }
+
+    #[inline]
+    fn same_elements(sorted: &[U], slice: &[U]) -> bool {
+        Self::sorted_copy(slice).is_some_and(|copy| copy == sorted)
+    }
+}
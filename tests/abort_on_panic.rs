@@ -0,0 +1,57 @@
+//! The methods of `DcRefCell` that abort if their closure panics on Debug.
+//!
+//! Each test runs itself again in a child process, which performs the call, and checks that the child aborted.
+#![cfg(debug_assertions)]
+
+use std::{env, process::Command};
+
+use enderlook_debug_checked::DcRefCell;
+
+/// Environment variable that tells the child process to perform the call.
+const CHILD: &str = "DC_ABORT_ON_PANIC_CHILD";
+
+/// Runs `call` in a child process executing only the test `name`, and asserts that the child aborted after the panic of the closure.
+fn assert_aborts(name: &str, call: impl FnOnce()) {
+    if env::var_os(CHILD).is_some() {
+        call();
+        // Unreachable if the call aborted.
+        return;
+    }
+
+    let output = Command::new(env::current_exe().unwrap())
+        .args([name, "--exact", "--nocapture", "--test-threads=1"])
+        .env(CHILD, "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("closure panicked"), "the closure didn't panic in the child process:\n{}", stderr);
+    assert!(!output.status.success(), "the child process didn't abort:\n{}", stderr);
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        assert_eq!(output.status.signal(), Some(6), "the child process wasn't killed by SIGABRT:\n{}", stderr);
+    }
+}
+
+#[test]
+fn replace_with_owned_dc_aborts_if_the_closure_panics() {
+    assert_aborts("replace_with_owned_dc_aborts_if_the_closure_panics", || {
+        let cell = DcRefCell::new(String::from("a"));
+        unsafe { cell.replace_with_owned_dc(|_| panic!("closure panicked")) };
+    });
+}
+
+#[test]
+fn with_borrow_mut_aborts_if_the_closure_panics() {
+    assert_aborts("with_borrow_mut_aborts_if_the_closure_panics", || {
+        let cell = DcRefCell::new(1);
+        unsafe { cell.with_borrow_mut(|_| panic!("closure panicked")) };
+    });
+}
+
+#[test]
+fn replace_with_owned_dc_replaces_the_value() {
+    let cell = DcRefCell::new(String::from("a"));
+    unsafe { cell.replace_with_owned_dc(|value| value + "b") };
+    assert_eq!(*unsafe { cell.borrow() }, "ab");
+}
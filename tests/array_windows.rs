@@ -0,0 +1,56 @@
+//! `DcSliceElements::array_windows_dc` yields the same windows as `windows` and manual indexing.
+
+use enderlook_debug_checked::DcSliceElements;
+
+/// Checks the windows of length `N` of every prefix of `values` that is at least `N` long.
+fn compare<const N: usize>(values: &[i32]) {
+    for len in N..=values.len() {
+        let slice = &values[..len];
+        let windows: Vec<&[i32; N]> = unsafe { slice.array_windows_dc::<N>() }.collect();
+
+        let expected: Vec<&[i32]> = slice.windows(N).collect();
+        assert_eq!(windows.len(), expected.len());
+        for (i, (window, other)) in windows.iter().zip(&expected).enumerate() {
+            assert_eq!(&window[..], *other);
+            assert_eq!(window.as_ptr(), slice[i..].as_ptr());
+            for j in 0..N {
+                assert_eq!(window[j], slice[i + j]);
+            }
+        }
+    }
+}
+
+#[test]
+fn same_windows_as_windows_and_indexing() {
+    let values: Vec<i32> = (0..9).map(|x| x * x - 3).collect();
+    compare::<1>(&values);
+    compare::<2>(&values);
+    compare::<3>(&values);
+    compare::<8>(&values);
+    compare::<9>(&values);
+}
+
+#[test]
+fn window_as_long_as_the_slice() {
+    let values = [1, 2, 3];
+    let mut windows = unsafe { values.array_windows_dc::<3>() };
+    assert_eq!(windows.next(), Some(&values));
+    assert_eq!(windows.next(), None);
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn zero_size() {
+        let _ = unsafe { [1, 2].array_windows_dc::<0>() };
+    }
+
+    #[test]
+    #[should_panic(expected = "slice of length 2 is shorter than the window size 3")]
+    fn shorter_than_the_window() {
+        let _ = unsafe { [1, 2].array_windows_dc::<3>() };
+    }
+}
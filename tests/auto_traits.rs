@@ -0,0 +1,43 @@
+//! Compile-time checks of the auto traits of `DcRefCell`, which must match `RefCell<T>` on both Debug and Release.
+//!
+//! This file only compiles if the assertions hold.
+
+use std::rc::Rc;
+
+use enderlook_debug_checked::DcRefCell;
+
+/// Compiles only if `T: Send`.
+const fn assert_send<T: ?Sized + Send>() {}
+
+/// Implemented twice for every `T: Send`, so naming `some_item` is ambiguous and fails to compile unless `T: !Send`.
+trait AmbiguousIfSend<A> {
+    fn some_item() {}
+}
+
+impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+
+impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+
+/// Implemented twice for every `T: Sync`, so naming `some_item` is ambiguous and fails to compile unless `T: !Sync`.
+trait AmbiguousIfSync<A> {
+    fn some_item() {}
+}
+
+impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+
+impl<T: ?Sized + Sync> AmbiguousIfSync<u8> for T {}
+
+const _: () = assert_send::<DcRefCell<i32>>();
+const _: () = assert_send::<DcRefCell<[i32]>>();
+
+const _: fn() = || {
+    let _ = <DcRefCell<Rc<i32>> as AmbiguousIfSend<_>>::some_item;
+};
+
+const _: fn() = || {
+    let _ = <DcRefCell<i32> as AmbiguousIfSync<_>>::some_item;
+};
+
+const _: fn() = || {
+    let _ = <DcRefCell<Rc<i32>> as AmbiguousIfSync<_>>::some_item;
+};
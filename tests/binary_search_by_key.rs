@@ -0,0 +1,37 @@
+//! `DcSliceElements::binary_search_by_key_dc` checks that the keys are sorted on Debug.
+
+use enderlook_debug_checked::DcSliceElements;
+
+/// Record sorted by `time`, which doesn't implement `Debug`.
+struct Event {
+    time: u32,
+}
+
+fn events(times: &[u32]) -> Vec<Event> {
+    times.iter().map(|&time| Event { time }).collect()
+}
+
+#[test]
+fn matches_std_on_sorted_keys() {
+    let events = events(&[1, 3, 3, 7, 9]);
+    for key in 0..=10 {
+        let expected = events.binary_search_by_key(&key, |event| event.time);
+        let actual = unsafe { events.binary_search_by_key_dc(&key, |event| event.time) };
+        match (actual, expected) {
+            (Ok(actual), Ok(_)) => assert_eq!(events[actual].time, key),
+            _ => assert_eq!(actual, expected),
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "slice is not sorted by key: key of element at index 2 is greater than key of element at index 3")]
+    fn unsorted_keys() {
+        let events = events(&[1, 3, 7, 5, 9]);
+        let _ = unsafe { events.binary_search_by_key_dc(&5, |event| event.time) };
+    }
+}
@@ -0,0 +1,34 @@
+//! `borrow_mut_all_dc!` rejects the same cell passed more than once on Debug, naming the positions of both arguments.
+
+use enderlook_debug_checked::{borrow_mut_all_dc, DcRefCell};
+
+#[test]
+fn borrows_distinct_cells() {
+    let (a, b, c) = (DcRefCell::new(1), DcRefCell::new(String::from("b")), DcRefCell::new(3));
+    unsafe {
+        let (mut a, mut b, mut c) = borrow_mut_all_dc!(&a, &b, &c);
+        *a += 10;
+        b.push('!');
+        std::mem::swap(&mut *a, &mut *c);
+    }
+    assert_eq!(unsafe { (*a.borrow(), b.borrow().clone(), *c.borrow()) }, (3, String::from("b!"), 11));
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "arguments 0 and 2 of `borrow_mut_all_dc!` are the same `DcRefCell`")]
+    fn same_cell_passed_twice() {
+        let (a, b) = (DcRefCell::new(1), DcRefCell::new(2));
+        unsafe { borrow_mut_all_dc!(&a, &b, &a) };
+    }
+
+    #[test]
+    #[should_panic(expected = "arguments 1 and 2 of `borrow_mut_all_dc!` are the same `DcRefCell`")]
+    fn same_cell_passed_in_adjacent_arguments() {
+        let (a, b) = (DcRefCell::new(1), DcRefCell::new(2));
+        unsafe { borrow_mut_all_dc!(&a, &b, &b) };
+    }
+}
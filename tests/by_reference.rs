@@ -0,0 +1,75 @@
+//! The by-reference extractors of `DcOption`, `DcResultOk` and `DcResultErr` work through `Deref` and `DerefMut` wrappers.
+
+use std::rc::Rc;
+
+use enderlook_debug_checked::{DcOption, DcRefCell, DcResultErr, DcResultOk};
+
+#[test]
+fn through_box() {
+    let mut option = Box::new(Some(vec![1]));
+    let mut result: Box<Result<String, u32>> = Box::new(Ok(String::from("a")));
+    let mut error: Box<Result<u32, String>> = Box::new(Err(String::from("e")));
+    unsafe {
+        option.unwrap_dc_mut().push(2);
+        assert_eq!(option.unwrap_dc_ref(), &[1, 2]);
+        assert_eq!(option.expect_dc_ref("message").len(), 2);
+
+        result.unwrap_dc_ok_mut().push('b');
+        assert_eq!(result.unwrap_dc_ok_ref(), "ab");
+        assert_eq!(result.expect_dc_ok_ref("message"), "ab");
+
+        error.expect_dc_err_mut("message").push('f');
+        assert_eq!(error.unwrap_dc_err_ref(), "ef");
+    }
+    // The wrappers still own their values.
+    assert_eq!(*option, Some(vec![1, 2]));
+}
+
+#[test]
+fn through_rc() {
+    let option = Rc::new(Some(String::from("a")));
+    let result: Rc<Result<u8, String>> = Rc::new(Ok(5));
+    let error: Rc<Result<u8, String>> = Rc::new(Err(String::from("e")));
+    let shared = Rc::clone(&option);
+    unsafe {
+        assert_eq!(option.unwrap_dc_ref(), "a");
+        assert_eq!(shared.expect_dc_ref("message"), "a");
+        assert_eq!(*result.unwrap_dc_ok_ref(), 5);
+        assert_eq!(error.expect_dc_err_ref("message"), "e");
+    }
+}
+
+#[test]
+fn through_ref_and_ref_mut() {
+    let option = DcRefCell::new(Some(1));
+    let result: DcRefCell<Result<u8, String>> = DcRefCell::new(Ok(1));
+    let error: DcRefCell<Result<u8, String>> = DcRefCell::new(Err(String::from("e")));
+    unsafe {
+        *option.borrow_mut().unwrap_dc_mut() += 1;
+        *result.borrow_mut().expect_dc_ok_mut("message") += 1;
+        error.borrow_mut().unwrap_dc_err_mut().push('f');
+
+        assert_eq!(*option.borrow().unwrap_dc_ref(), 2);
+        assert_eq!(*result.borrow().unwrap_dc_ok_ref(), 2);
+        assert_eq!(error.borrow().unwrap_dc_err_ref(), "ef");
+    }
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "called `Option::unwrap()` on a `None` value")]
+    fn none_through_box() {
+        let option: Box<Option<u8>> = Box::new(None);
+        unsafe { option.unwrap_dc_ref() };
+    }
+
+    #[test]
+    #[should_panic(expected = "message")]
+    fn err_through_ref_mut() {
+        let result: DcRefCell<Result<u8, String>> = DcRefCell::new(Err(String::from("e")));
+        unsafe { *result.borrow_mut().expect_dc_ok_mut("message") += 1 };
+    }
+}
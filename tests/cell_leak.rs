@@ -0,0 +1,35 @@
+//! Leaking guards of `DcRefCell` and recovering with `undo_leak`, with the `cell_leak` feature.
+#![cfg(feature = "cell_leak")]
+
+use enderlook_debug_checked::{DcRefCell, Ref, RefMut};
+
+#[test]
+fn undo_leak_after_leaking_a_ref() {
+    let mut cell = DcRefCell::new(1);
+    let leaked = Ref::leak(unsafe { cell.borrow() });
+    assert_eq!(*leaked, 1);
+
+    *cell.undo_leak() += 1;
+    *unsafe { cell.borrow_mut() } += 1;
+    assert_eq!(*unsafe { cell.borrow() }, 3);
+}
+
+#[test]
+fn undo_leak_after_leaking_a_ref_mut() {
+    let mut cell = DcRefCell::new(1);
+    let leaked = RefMut::leak(unsafe { cell.borrow_mut() });
+    assert_eq!(*leaked, 1);
+
+    *cell.undo_leak() += 1;
+    *unsafe { cell.borrow_mut() } += 1;
+    assert_eq!(*unsafe { cell.borrow() }, 3);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "BorrowMutError")]
+fn leaked_ref_blocks_borrow_mut_until_undone() {
+    let cell = DcRefCell::new(1);
+    Ref::leak(unsafe { cell.borrow() });
+    let _ = unsafe { cell.borrow_mut() };
+}
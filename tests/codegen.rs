@@ -0,0 +1,266 @@
+//! Checks the machine code generated on Release.
+//!
+//! Each test compiles a fixture of `#[inline(never)]` wrappers against the Release build of the crate with `rustc --emit asm`
+//! and compares the instructions of the wrappers.
+#![cfg(all(not(debug_assertions), target_arch = "x86_64"))]
+
+use std::{collections::HashMap, env, fs, process::Command};
+
+/// Compiles `source` as a library depending on this crate, with the optimizations of Release, and returns its assembly.
+fn emit_asm(name: &str, source: &str) -> String {
+    let deps = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let rlib = fs::read_dir(&deps)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let file = path.file_name().unwrap().to_string_lossy();
+            file.starts_with("libenderlook_debug_checked-") && file.ends_with(".rlib")
+        })
+        .max_by_key(|path| fs::metadata(path).unwrap().modified().unwrap())
+        .expect("the rlib of the crate wasn't found next to the test executable");
+
+    let dir = deps.join(format!("codegen-{}", name));
+    fs::create_dir_all(&dir).unwrap();
+    let fixture = dir.join("fixture.rs");
+    let asm = dir.join("fixture.s");
+    fs::write(&fixture, source).unwrap();
+
+    let output = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()))
+        .args(["--edition=2021", "--crate-type=lib", "--emit=asm", "-Copt-level=3", "-Cdebuginfo=0", "-Cllvm-args=-x86-asm-syntax=intel"])
+        .arg("--extern")
+        .arg(format!("enderlook_debug_checked={}", rlib.display()))
+        .arg("-L")
+        .arg(format!("dependency={}", deps.display()))
+        .arg("-o")
+        .arg(&asm)
+        .arg(&fixture)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "failed to compile the fixture:\n{}", String::from_utf8_lossy(&output.stderr));
+    fs::read_to_string(&asm).unwrap()
+}
+
+/// Returns the instructions of the function `name`, without directives nor comments,
+/// and with the local symbols renamed by order of appearance so two functions can be compared.
+///
+/// Functions merged by the optimizer into an alias of an identical function return the instructions of that function.
+fn instructions(asm: &str, name: &str) -> Vec<String> {
+    let alias = format!("{} = ", name);
+    if let Some(line) = asm.lines().find(|line| line.starts_with(&alias)) {
+        return instructions(asm, line[alias.len()..].trim());
+    }
+
+    let start = format!("{}:", name);
+    let mut lines = asm.lines().skip_while(|line| *line != start);
+    assert!(lines.next().is_some(), "function `{}` not found in the assembly", name);
+
+    let mut symbols = HashMap::new();
+    let mut instructions = Vec::new();
+    for line in lines {
+        let line = line.split('#').next().unwrap().trim();
+        if line.starts_with(".Lfunc_end") {
+            break;
+        }
+        if line.is_empty() || (line.starts_with('.') && !line.ends_with(':')) {
+            continue;
+        }
+
+        let mut renamed = String::new();
+        let mut rest = line;
+        while let Some(i) = rest.find(".L") {
+            renamed.push_str(&rest[..i]);
+            let end = rest[i..].find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.')).map_or(rest.len(), |end| i + end);
+            let next = symbols.len();
+            let id = *symbols.entry(rest[i..end].to_string()).or_insert(next);
+            renamed.push_str(&format!(".L{}", id));
+            rest = &rest[end..];
+        }
+        renamed.push_str(rest);
+        instructions.push(renamed);
+    }
+    instructions
+}
+
+/// Asserts that the functions `a` and `b` are compiled to the same instructions.
+fn assert_same_code(asm: &str, a: &str, b: &str) {
+    let (code_a, code_b) = (instructions(asm, a), instructions(asm, b));
+    assert!(code_a == code_b, "`{}` and `{}` compile to different code:\n{:#?}\n{:#?}", a, b, code_a, code_b);
+}
+
+/// Asserts that the function `name` only moves values, without any comparison nor branch.
+fn assert_only_moves(asm: &str, name: &str) {
+    let code = instructions(asm, name);
+    let mnemonic = |instruction: &String| instruction.split_whitespace().next().unwrap().to_string();
+    assert!(
+        code.iter().map(mnemonic).all(|mnemonic| ["mov", "movzx", "and", "ret"].contains(&mnemonic.as_str())),
+        "`{}` doesn't compile to a plain move:\n{:#?}",
+        name,
+        code
+    );
+}
+
+#[test]
+fn dc_methods_generate_the_same_code_as_unchecked_methods() {
+    let asm = emit_asm(
+        "unchecked",
+        r#"
+use std::ops::Range;
+use enderlook_debug_checked::{DcOption, DcResultOk, DcSlice};
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn get_dc(slice: &[u64], index: usize) -> u64 {
+    *slice.get_dc(index)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn get_unchecked(slice: &[u64], index: usize) -> u64 {
+    *slice.get_unchecked(index)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn get_dc_range(slice: &[u64], range: Range<usize>) -> &[u64] {
+    slice.get_dc(range)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn get_unchecked_range(slice: &[u64], range: Range<usize>) -> &[u64] {
+    slice.get_unchecked(range)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn get_dc_mut(slice: &mut [u64], index: usize) {
+    *slice.get_dc_mut(index) += 1;
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn get_unchecked_mut(slice: &mut [u64], index: usize) {
+    *slice.get_unchecked_mut(index) += 1;
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn unwrap_dc(option: Option<u64>) -> u64 {
+    option.unwrap_dc()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn option_unwrap_unchecked(option: Option<u64>) -> u64 {
+    option.unwrap_unchecked()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn unwrap_dc_ok(result: Result<u64, u32>) -> u64 {
+    result.unwrap_dc_ok()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn result_unwrap_unchecked(result: Result<u64, u32>) -> u64 {
+    result.unwrap_unchecked()
+}
+"#,
+    );
+    assert_same_code(&asm, "get_dc", "get_unchecked");
+    assert_same_code(&asm, "get_dc_range", "get_unchecked_range");
+    assert_same_code(&asm, "get_dc_mut", "get_unchecked_mut");
+    assert_same_code(&asm, "unwrap_dc", "option_unwrap_unchecked");
+    assert_same_code(&asm, "unwrap_dc_ok", "result_unwrap_unchecked");
+}
+
+#[test]
+fn unwrap_dc_of_niche_optimized_options_is_a_plain_move() {
+    let asm = emit_asm(
+        "niche",
+        r#"
+use std::num::NonZeroU8;
+use enderlook_debug_checked::DcOption;
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn unwrap_dc_bool(option: Option<bool>) -> bool {
+    option.unwrap_dc()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn unwrap_dc_char(option: Option<char>) -> char {
+    option.unwrap_dc()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn unwrap_dc_non_zero_u8(option: Option<NonZeroU8>) -> NonZeroU8 {
+    option.unwrap_dc()
+}
+"#,
+    );
+    assert_only_moves(&asm, "unwrap_dc_bool");
+    assert_only_moves(&asm, "unwrap_dc_char");
+    assert_only_moves(&asm, "unwrap_dc_non_zero_u8");
+}
+
+#[test]
+fn unwrap_dc_generates_the_same_code_as_matching_with_unreachable_dc() {
+    let asm = emit_asm(
+        "unreachable",
+        r#"
+use enderlook_debug_checked::{unreachable_dc, DcOption};
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn unwrap_dc_u64(option: Option<u64>) -> u64 {
+    option.unwrap_dc()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn match_u64(option: Option<u64>) -> u64 {
+    match option {
+        Some(value) => value,
+        None => unreachable_dc(),
+    }
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn unwrap_dc_box(option: Option<Box<u8>>) -> Box<u8> {
+    option.unwrap_dc()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn match_box(option: Option<Box<u8>>) -> Box<u8> {
+    match option {
+        Some(value) => value,
+        None => unreachable_dc(),
+    }
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn unwrap_dc_string(option: Option<String>) -> String {
+    option.unwrap_dc()
+}
+
+#[no_mangle]
+#[inline(never)]
+pub unsafe fn match_string(option: Option<String>) -> String {
+    match option {
+        Some(value) => value,
+        None => unreachable_dc(),
+    }
+}
+"#,
+    );
+    assert_same_code(&asm, "unwrap_dc_u64", "match_u64");
+    assert_same_code(&asm, "unwrap_dc_box", "match_box");
+    assert_same_code(&asm, "unwrap_dc_string", "match_string");
+}
@@ -0,0 +1,55 @@
+//! `DcControlFlowContinue` and `DcControlFlowBreak` show the content of the unexpected variant on Debug.
+
+use std::ops::ControlFlow;
+
+use enderlook_debug_checked::{DcControlFlowBreak, DcControlFlowContinue};
+
+#[test]
+fn extracts_the_expected_variant() {
+    let flow: ControlFlow<String, u8> = ControlFlow::Continue(1);
+    assert_eq!(unsafe { flow.unwrap_continue_dc() }, 1);
+    let flow: ControlFlow<String, u8> = ControlFlow::Break(String::from("b"));
+    assert_eq!(unsafe { flow.expect_break_dc("message") }, "b");
+}
+
+/// On Release, the payload of the other variant doesn't need to implement `Debug`.
+#[cfg(not(debug_assertions))]
+#[test]
+fn payload_without_debug_on_release() {
+    struct Opaque;
+    let flow: ControlFlow<Opaque, u8> = ControlFlow::Continue(1);
+    assert_eq!(unsafe { flow.unwrap_continue_dc() }, 1);
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "called `unwrap_continue_dc()` on a `Break` value: \"stop\"")]
+    fn unwrap_continue_dc_on_break() {
+        let flow: ControlFlow<&str, u8> = ControlFlow::Break("stop");
+        unsafe { flow.unwrap_continue_dc() };
+    }
+
+    #[test]
+    #[should_panic(expected = "message: \"stop\"")]
+    fn expect_continue_dc_on_break() {
+        let flow: ControlFlow<&str, u8> = ControlFlow::Break("stop");
+        unsafe { flow.expect_continue_dc("message") };
+    }
+
+    #[test]
+    #[should_panic(expected = "called `unwrap_break_dc()` on a `Continue` value: [1, 2]")]
+    fn unwrap_break_dc_on_continue() {
+        let flow: ControlFlow<u8, Vec<u8>> = ControlFlow::Continue(vec![1, 2]);
+        unsafe { flow.unwrap_break_dc() };
+    }
+
+    #[test]
+    #[should_panic(expected = "message: [1, 2]")]
+    fn expect_break_dc_on_continue() {
+        let flow: ControlFlow<u8, Vec<u8>> = ControlFlow::Continue(vec![1, 2]);
+        unsafe { flow.expect_break_dc("message") };
+    }
+}
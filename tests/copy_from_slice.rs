@@ -0,0 +1,63 @@
+//! `DcSliceElements::copy_from_slice_dc` requires slices of the same length that don't overlap.
+
+use enderlook_debug_checked::DcSliceElements;
+
+#[test]
+fn copy_between_disjoint_slices() {
+    let mut values = vec![1, 2, 3, 4, 5, 6];
+    let (dst, src) = values.split_at_mut(3);
+    unsafe { dst.copy_from_slice_dc(src) };
+    assert_eq!(values, [4, 5, 6, 4, 5, 6]);
+}
+
+#[test]
+fn copy_between_adjacent_subslices_of_one_vec() {
+    let mut values = vec![1, 2, 3, 4];
+    let ptr = values.as_mut_ptr();
+    unsafe {
+        // The ranges `0..2` and `2..4` touch but don't overlap.
+        let src = std::slice::from_raw_parts(ptr.add(2), 2);
+        let dst = std::slice::from_raw_parts_mut(ptr, 2);
+        dst.copy_from_slice_dc(src);
+    }
+    assert_eq!(values, [3, 4, 3, 4]);
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "source slice length (2) does not match destination slice length (3)")]
+    fn different_lengths() {
+        unsafe { [0; 3].copy_from_slice_dc(&[1, 2]) };
+    }
+
+    /// Overlapping subslices of one `Vec` are undefined behavior that the check reports on Debug, so this can't run under Miri.
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    #[should_panic(expected = ") overlap")]
+    fn overlapping_subslices_of_one_vec() {
+        let mut values = vec![1, 2, 3, 4];
+        let ptr = values.as_mut_ptr();
+        unsafe {
+            let src = std::slice::from_raw_parts(ptr.add(1), 3);
+            let dst = std::slice::from_raw_parts_mut(ptr, 3);
+            dst.copy_from_slice_dc(src);
+        }
+    }
+
+    /// See `overlapping_subslices_of_one_vec`.
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    #[should_panic(expected = ") overlap")]
+    fn identical_subslices_of_one_vec() {
+        let mut values = vec![1, 2, 3, 4];
+        let ptr = values.as_mut_ptr();
+        unsafe {
+            let src = std::slice::from_raw_parts(ptr, 4);
+            let dst = std::slice::from_raw_parts_mut(ptr, 4);
+            dst.copy_from_slice_dc(src);
+        }
+    }
+}
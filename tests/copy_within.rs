@@ -0,0 +1,79 @@
+//! `DcSliceElements::copy_within_dc` and `DcSliceElements::copy_within_nonoverlapping_dc` copy like `copy_within`.
+
+use enderlook_debug_checked::DcSliceElements;
+
+#[test]
+fn copy_within_dc_in_range() {
+    let mut values = [1, 2, 3, 4, 5];
+    unsafe { values.copy_within_dc(0..2, 3) };
+    assert_eq!(values, [1, 2, 3, 1, 2]);
+}
+
+#[test]
+fn copy_within_dc_with_overlapping_ranges() {
+    let mut values = [1, 2, 3, 4, 5];
+    unsafe { values.copy_within_dc(0..4, 1) };
+    assert_eq!(values, [1, 1, 2, 3, 4]);
+
+    let mut values = [1, 2, 3, 4, 5];
+    unsafe { values.copy_within_dc(1..5, 0) };
+    assert_eq!(values, [2, 3, 4, 5, 5]);
+}
+
+#[test]
+fn copy_within_nonoverlapping_dc_in_range() {
+    let mut values = [1, 2, 3, 4, 5];
+    unsafe { values.copy_within_nonoverlapping_dc(3..5, 0) };
+    assert_eq!(values, [4, 5, 3, 4, 5]);
+
+    // Adjacent ranges don't overlap.
+    let mut values = [1, 2, 3, 4];
+    unsafe { values.copy_within_nonoverlapping_dc(0..2, 2) };
+    assert_eq!(values, [1, 2, 1, 2]);
+}
+
+#[test]
+fn empty_copies_at_the_end() {
+    let mut values = [1, 2, 3];
+    unsafe {
+        values.copy_within_dc(3..3, 3);
+        values.copy_within_nonoverlapping_dc(3..3, 3);
+        values.copy_within_nonoverlapping_dc(1..1, 1);
+    }
+    assert_eq!(values, [1, 2, 3]);
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for slice of length 5")]
+    fn copy_within_dc_source_out_of_range() {
+        unsafe { [1, 2, 3, 4, 5].copy_within_dc(3..6, 0) };
+    }
+
+    #[test]
+    #[should_panic(expected = "dest is out of bounds")]
+    fn copy_within_dc_destination_out_of_range() {
+        unsafe { [1, 2, 3, 4, 5].copy_within_dc(0..2, 4) };
+    }
+
+    #[test]
+    #[should_panic(expected = "source range 3..6 out of range for slice of length 5")]
+    fn copy_within_nonoverlapping_dc_source_out_of_range() {
+        unsafe { [1, 2, 3, 4, 5].copy_within_nonoverlapping_dc(3..6, 0) };
+    }
+
+    #[test]
+    #[should_panic(expected = "destination index 4 out of range for a copy of 2 elements in a slice of length 5")]
+    fn copy_within_nonoverlapping_dc_destination_out_of_range() {
+        unsafe { [1, 2, 3, 4, 5].copy_within_nonoverlapping_dc(0..2, 4) };
+    }
+
+    #[test]
+    #[should_panic(expected = "source range 0..3 and destination range 2..5 overlap")]
+    fn copy_within_nonoverlapping_dc_overlapping_ranges() {
+        unsafe { [1, 2, 3, 4, 5].copy_within_nonoverlapping_dc(0..3, 2) };
+    }
+}
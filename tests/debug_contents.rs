@@ -0,0 +1,27 @@
+//! Formatting of `DcRefCell` with the `debug_contents` feature.
+#![cfg(feature = "debug_contents")]
+
+use enderlook_debug_checked::DcRefCell;
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn debug_prints_the_type_name_on_release() {
+    let cell = DcRefCell::new(5_i32);
+    let _guard = unsafe { cell.borrow_mut() };
+    assert_eq!(format!("{:?}", cell), r#"DcRefCell { type: "i32", .. }"#);
+}
+
+#[test]
+fn debug_contents_dc_prints_the_value() {
+    let cell = DcRefCell::new(vec![1, 2]);
+    assert_eq!(format!("{:?}", unsafe { cell.debug_contents_dc() }), "[1, 2]");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "BorrowError")]
+fn debug_contents_dc_panics_while_mutably_borrowed_on_debug() {
+    let cell = DcRefCell::new(5_i32);
+    let _guard = unsafe { cell.borrow_mut() };
+    unsafe { cell.debug_contents_dc() };
+}
@@ -0,0 +1,38 @@
+//! `DcVec::dedup_by_key_sorted_dc` checks that the vector is grouped by key on Debug.
+
+use enderlook_debug_checked::DcVec;
+
+/// Event keyed by `id`, which doesn't implement `Debug`.
+struct Event {
+    id: u32,
+    payload: char,
+}
+
+fn events(ids: &[u32]) -> Vec<Event> {
+    ids.iter().zip('a'..).map(|(&id, payload)| Event { id, payload }).collect()
+}
+
+#[test]
+fn matches_std_on_grouped_keys() {
+    let mut actual = events(&[1, 1, 3, 2, 2, 2, 5]);
+    let mut expected = events(&[1, 1, 3, 2, 2, 2, 5]);
+    unsafe { actual.dedup_by_key_sorted_dc(|event| event.id) };
+    expected.dedup_by_key(|event| event.id);
+    let summary = |events: &[Event]| events.iter().map(|event| (event.id, event.payload)).collect::<Vec<_>>();
+    assert_eq!(summary(&actual), summary(&expected));
+    assert_eq!(summary(&actual), [(1, 'a'), (3, 'c'), (2, 'd'), (5, 'g')]);
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(
+        expected = "vector is not grouped by key: key of element at index 4 equals key of element at index 1, but a different key appeared at index 3"
+    )]
+    fn key_reappearing_after_a_different_group() {
+        let mut events = events(&[0, 1, 1, 2, 1]);
+        unsafe { events.dedup_by_key_sorted_dc(|event| event.id) };
+    }
+}
@@ -0,0 +1,32 @@
+//! `DcSlice::get_dc_or` must build and behave the same with and without the `slice_index_methods` feature.
+
+use enderlook_debug_checked::DcSlice;
+
+#[test]
+fn in_range_returns_the_element() {
+    let values: &[i32] = &[1, 2, 3];
+    unsafe {
+        assert_eq!(*values.get_dc_or(1, &0), 2);
+        assert_eq!(values.get_dc_or(1.., &[]), &[2, 3]);
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 3 but the index is 7")]
+fn out_of_range_panics_on_debug() {
+    let values: &[i32] = &[1, 2, 3];
+    unsafe {
+        values.get_dc_or(7, &0);
+    }
+}
+
+#[cfg(all(not(debug_assertions), feature = "soft_release"))]
+#[test]
+fn out_of_range_returns_the_fallback_with_soft_release() {
+    let values: &[i32] = &[1, 2, 3];
+    unsafe {
+        assert_eq!(*values.get_dc_or(7, &0), 0);
+        assert_eq!(values.get_dc_or(2..5, &[]), &[] as &[i32]);
+    }
+}
@@ -0,0 +1,65 @@
+//! `DcRefCell::get_mut`.
+
+use std::fmt::Display;
+
+use enderlook_debug_checked::DcRefCell;
+
+#[test]
+fn get_mut_of_unsized_slice() {
+    let mut cell: Box<DcRefCell<[i32]>> = Box::new(DcRefCell::new([1, 2, 3]));
+    let slice = cell.get_mut();
+    assert_eq!(slice.len(), 3);
+    slice[1] = 20;
+    slice.reverse();
+    assert_eq!(&*unsafe { cell.borrow() }, &[3, 20, 1]);
+}
+
+#[test]
+fn get_mut_of_unsized_trait_object() {
+    let mut cell: Box<DcRefCell<dyn Display>> = Box::new(DcRefCell::new(5_u8));
+    assert_eq!(cell.get_mut().to_string(), "5");
+
+    let mut cell: Box<DcRefCell<dyn Iterator<Item = u8>>> = Box::new(DcRefCell::new(1..4));
+    assert_eq!(cell.get_mut().next(), Some(1));
+    assert_eq!(cell.get_mut().collect::<Vec<_>>(), [2, 3]);
+}
+
+#[test]
+fn get_mut_leaves_no_borrow_behind() {
+    let mut cell = DcRefCell::new(vec![1]);
+    cell.get_mut().push(2);
+    {
+        let value = cell.get_mut();
+        value.push(3);
+    }
+
+    unsafe {
+        assert_eq!(*cell.borrow(), [1, 2, 3]);
+        cell.borrow_mut().push(4);
+        let a = cell.borrow();
+        let b = cell.borrow();
+        assert_eq!(*a, *b);
+    }
+    assert_eq!(cell.get_mut(), &[1, 2, 3, 4]);
+}
+
+#[test]
+fn get_mut_after_borrows_are_dropped() {
+    let mut cell = DcRefCell::new(0);
+    unsafe {
+        *cell.borrow_mut() += 1;
+        let _ = *cell.borrow();
+    }
+    *cell.get_mut() += 1;
+    assert_eq!(*unsafe { cell.borrow_mut() }, 2);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "BorrowMutError")]
+fn get_mut_doesnt_reset_a_leaked_borrow() {
+    let mut cell = DcRefCell::new(0);
+    std::mem::forget(unsafe { cell.borrow() });
+    *cell.get_mut() += 1;
+    let _ = unsafe { cell.borrow_mut() };
+}
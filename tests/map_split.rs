@@ -0,0 +1,56 @@
+//! `RefMut::map_split` splits a mutable borrow of a `DcRefCell` into disjoint components.
+
+use enderlook_debug_checked::{DcRefCell, RefMut};
+
+struct Pair {
+    name: String,
+    values: Vec<i32>,
+}
+
+#[test]
+fn disjoint_fields() {
+    let cell = DcRefCell::new(Pair { name: String::from("a"), values: vec![1] });
+    {
+        let (mut name, mut values) = RefMut::map_split(unsafe { cell.borrow_mut() }, |pair| (&mut pair.name, &mut pair.values));
+        name.push('b');
+        values.push(2);
+    }
+    let pair = unsafe { cell.borrow() };
+    assert_eq!((pair.name.as_str(), pair.values.as_slice()), ("ab", &[1, 2][..]));
+}
+
+#[test]
+fn disjoint_halves_of_an_array() {
+    let cell = DcRefCell::new([1, 2, 3, 4]);
+    {
+        let (mut a, mut b) = RefMut::map_split(unsafe { cell.borrow_mut() }, |array| array.split_at_mut(2));
+        a.swap(0, 1);
+        b[1] = 40;
+    }
+    assert_eq!(*unsafe { cell.borrow() }, [2, 1, 3, 40]);
+}
+
+#[test]
+fn disjoint_data_owned_indirectly() {
+    let cell = DcRefCell::new((vec![1, 2], 3));
+    {
+        let (mut buffer, mut value) = RefMut::map_split(unsafe { cell.borrow_mut() }, |(vec, value)| (vec.as_mut_slice(), value));
+        buffer[0] = 10;
+        *value = 30;
+    }
+    assert_eq!(*unsafe { cell.borrow() }, (vec![10, 2], 30));
+}
+
+/// The closure aliases the borrowed value through unsafe code, which is undefined behavior that the check reports on Debug,
+/// so this can't run under Miri.
+#[cfg(debug_assertions)]
+#[cfg_attr(miri, ignore)]
+#[test]
+#[should_panic(expected = "`map_split` returned overlapping references")]
+fn overlapping_halves_of_an_array() {
+    let cell = DcRefCell::new([1, 2, 3, 4]);
+    let _ = RefMut::map_split(unsafe { cell.borrow_mut() }, |array| {
+        let ptr = array.as_mut_ptr();
+        unsafe { (std::slice::from_raw_parts_mut(ptr, 3), std::slice::from_raw_parts_mut(ptr.add(2), 2)) }
+    });
+}
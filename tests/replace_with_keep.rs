@@ -0,0 +1,37 @@
+//! `DcRefCell::replace_with_keep_dc` keeps the value of the cell when the closure panics.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use enderlook_debug_checked::DcRefCell;
+
+#[test]
+fn returns_the_old_value() {
+    let cell = DcRefCell::new(vec![1]);
+    let old = unsafe { cell.replace_with_keep_dc(|value| value.iter().map(|x| x * 2).collect()) };
+    assert_eq!(old, [1]);
+    assert_eq!(*unsafe { cell.borrow() }, [2]);
+}
+
+#[test]
+fn keeps_the_value_when_the_closure_panics() {
+    let cell = DcRefCell::new(vec![1, 2]);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+        cell.replace_with_keep_dc(|value| {
+            value.push(3);
+            panic!("replacement failed")
+        })
+    }));
+    assert!(result.is_err());
+    // The modification done before panicking is kept, and the borrow was released while unwinding.
+    assert_eq!(*unsafe { cell.borrow_mut() }, [1, 2, 3]);
+}
+
+#[test]
+fn keeps_the_value_when_the_closure_panics_with_expect() {
+    let cell = DcRefCell::new(String::from("a"));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+        cell.replace_with_keep_dc_expect(|_| panic!("replacement failed"), "message")
+    }));
+    assert!(result.is_err());
+    assert_eq!(*unsafe { cell.borrow_mut() }, "a");
+}
@@ -0,0 +1,70 @@
+//! `DcSliceElements::reset_range_dc` matches a safe reference implementation.
+
+use std::{cell::Cell, ops::Range};
+
+use enderlook_debug_checked::DcSliceElements;
+
+/// Safe reference implementation of `reset_range_dc`.
+fn reset_range<T: Default>(slice: &mut [T], range: Range<usize>) {
+    for i in range {
+        slice[i] = T::default();
+    }
+}
+
+#[test]
+fn matches_the_reference_implementation_for_every_range() {
+    let values: Vec<String> = (0..6).map(|i| i.to_string()).collect();
+    for start in 0..=values.len() {
+        for end in start..=values.len() {
+            let (mut actual, mut expected) = (values.clone(), values.clone());
+            unsafe { actual.reset_range_dc(start..end) };
+            reset_range(&mut expected, start..end);
+            assert_eq!(actual, expected, "range {:?}", start..end);
+        }
+    }
+}
+
+/// Counts the values dropped, and it isn't `Clone`.
+#[derive(Default)]
+struct Slot<'a>(Option<&'a Cell<usize>>);
+
+impl Drop for Slot<'_> {
+    fn drop(&mut self) {
+        if let Some(drops) = self.0 {
+            drops.set(drops.get() + 1);
+        }
+    }
+}
+
+#[test]
+fn drops_the_same_values_as_the_reference_implementation() {
+    let (actual_drops, expected_drops) = (Cell::new(0), Cell::new(0));
+    let mut actual: Vec<Slot> = (0..5).map(|_| Slot(Some(&actual_drops))).collect();
+    let mut expected: Vec<Slot> = (0..5).map(|_| Slot(Some(&expected_drops))).collect();
+
+    unsafe { actual.reset_range_dc(1..4) };
+    reset_range(&mut expected, 1..4);
+
+    assert_eq!(actual_drops.get(), 3);
+    assert_eq!(actual_drops.get(), expected_drops.get());
+    let reset = |slots: &[Slot]| slots.iter().map(|slot| slot.0.is_none()).collect::<Vec<_>>();
+    assert_eq!(reset(&actual), reset(&expected));
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "range 2..7 is out of range for slice of length 6")]
+    fn end_out_of_range() {
+        unsafe { [0; 6].reset_range_dc(2..7) };
+    }
+
+    #[test]
+    #[should_panic(expected = "range 4..3 is out of range for slice of length 6")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn start_greater_than_end() {
+        unsafe { [0; 6].reset_range_dc(4..3) };
+    }
+}
@@ -0,0 +1,60 @@
+//! `DcSliceElements::rotate_left_dc` and `DcSliceElements::rotate_right_dc`.
+
+use enderlook_debug_checked::DcSliceElements;
+
+#[test]
+fn rotations_match_std() {
+    for mid in 0..=5 {
+        let mut expected = [1, 2, 3, 4, 5];
+        expected.rotate_left(mid);
+        let mut left = [1, 2, 3, 4, 5];
+        unsafe { left.rotate_left_dc(mid) };
+        assert_eq!(left, expected);
+
+        let mut expected = [1, 2, 3, 4, 5];
+        expected.rotate_right(mid);
+        let mut right = [1, 2, 3, 4, 5];
+        unsafe { right.rotate_right_dc(mid) };
+        assert_eq!(right, expected);
+    }
+}
+
+/// With the `heavy_checks` feature, the elements are only compared when they are `Ord + Clone`,
+/// so rotations of other elements must keep working.
+#[test]
+fn rotations_of_elements_which_are_not_ord_nor_clone() {
+    #[derive(Debug, PartialEq)]
+    struct Opaque(f64);
+
+    let mut values = [Opaque(1.0), Opaque(f64::NAN), Opaque(3.0)];
+    unsafe {
+        values.rotate_left_dc(1);
+        values.rotate_right_dc(2);
+    }
+    assert_eq!(values[0], Opaque(3.0));
+    assert!(values[1].0 == 1.0 && values[2].0.is_nan());
+}
+
+#[test]
+fn rotations_of_ord_elements() {
+    let mut values: Vec<String> = ["a", "b", "b", "c"].iter().map(|x| x.to_string()).collect();
+    unsafe {
+        values.rotate_left_dc(3);
+        values.rotate_right_dc(1);
+    }
+    assert_eq!(values, ["b", "c", "a", "b"]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "rotation amount 6 is out of range for slice of length 5")]
+fn rotate_left_out_of_range_panics_on_debug() {
+    unsafe { [1, 2, 3, 4, 5].rotate_left_dc(6) };
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "rotation amount 6 is out of range for slice of length 5")]
+fn rotate_right_out_of_range_panics_on_debug() {
+    unsafe { [1, 2, 3, 4, 5].rotate_right_dc(6) };
+}
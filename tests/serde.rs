@@ -0,0 +1,47 @@
+//! Serialization and deserialization of `DcRefCell` with the `serde` feature.
+#![cfg(feature = "serde")]
+
+use enderlook_debug_checked::DcRefCell;
+use serde::Deserialize;
+
+#[test]
+fn round_trip_through_ref() {
+    let cell = DcRefCell::new(vec![String::from("a"), String::from("b")]);
+    let json = serde_json::to_string(&unsafe { cell.borrow() }).unwrap();
+    assert_eq!(json, r#"["a","b"]"#);
+
+    let mut back: DcRefCell<Vec<String>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.get_mut(), &["a", "b"]);
+}
+
+#[test]
+fn round_trip_through_ref_mut() {
+    let cell = DcRefCell::new((1_u8, Some(2.5_f64)));
+    let json = serde_json::to_string(&unsafe { cell.borrow_mut() }).unwrap();
+    assert_eq!(json, "[1,2.5]");
+
+    let back: DcRefCell<(u8, Option<f64>)> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.into_inner(), (1, Some(2.5)));
+}
+
+#[test]
+fn deserialize_in_place_reuses_the_existing_value() {
+    let mut cell = DcRefCell::new(Vec::<u32>::with_capacity(16));
+    let buffer = cell.get_mut().as_ptr();
+
+    let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3]");
+    DcRefCell::deserialize_in_place(&mut deserializer, &mut cell).unwrap();
+    deserializer.end().unwrap();
+
+    assert_eq!(cell.get_mut(), &[1, 2, 3]);
+    assert_eq!(cell.get_mut().as_ptr(), buffer);
+    // No borrow is left behind by the deserialization.
+    assert_eq!(*unsafe { cell.borrow_mut() }, [1, 2, 3]);
+}
+
+#[test]
+fn deserialize_in_place_reports_errors() {
+    let mut cell = DcRefCell::new(vec![7_u32]);
+    let mut deserializer = serde_json::Deserializer::from_str(r#"["a"]"#);
+    assert!(DcRefCell::deserialize_in_place(&mut deserializer, &mut cell).is_err());
+}
@@ -0,0 +1,45 @@
+//! `RefMut::as_slice_of_cells` allows mutating elements through shared references while the `DcRefCell` stays mutably borrowed.
+
+use std::cell::Cell;
+
+use enderlook_debug_checked::{DcRefCell, RefMut};
+
+#[test]
+fn mutate_through_shared_cells() {
+    let cell = DcRefCell::new(vec![1, 2, 3, 4]);
+    {
+        let cells = RefMut::as_slice_of_cells(RefMut::map(unsafe { cell.borrow_mut() }, |vec| vec.as_mut_slice()));
+        let shared: &[Cell<i32>] = &cells;
+        let (first, also_first, last) = (&shared[0], &shared[0], &shared[3]);
+
+        first.set(10);
+        assert_eq!(also_first.get(), 10);
+        also_first.swap(last);
+        for (element, other) in shared.iter().zip(shared.iter().rev()) {
+            element.set(element.get() + other.get());
+        }
+        assert_eq!(shared.iter().map(Cell::get).collect::<Vec<_>>(), [14, 5, 8, 24]);
+    }
+    assert_eq!(*unsafe { cell.borrow() }, [14, 5, 8, 24]);
+}
+
+#[test]
+fn cells_of_an_unsized_borrow() {
+    let cell = DcRefCell::new([1_u8, 2, 3]);
+    {
+        let slice: RefMut<'_, [u8]> = unsafe { cell.borrow_mut() };
+        let cells = RefMut::as_slice_of_cells(slice);
+        cells.iter().for_each(|element| element.set(element.get() * 2));
+    }
+    assert_eq!(*unsafe { cell.borrow() }, [2, 4, 6]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "BorrowError")]
+fn cell_stays_mutably_borrowed() {
+    let cell = DcRefCell::new([1, 2]);
+    let cells = RefMut::as_slice_of_cells(unsafe { cell.borrow_mut() } as RefMut<'_, [i32]>);
+    cells[0].set(3);
+    let _ = unsafe { cell.borrow() };
+}
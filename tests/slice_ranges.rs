@@ -0,0 +1,73 @@
+//! `DcSlice::get_dc` with each kind of index reports the violated bound and the length of the slice on Debug.
+
+use enderlook_debug_checked::DcSlice;
+
+const VALUES: &[i32] = &[1, 2, 3];
+
+#[test]
+fn in_range_indexes_return_the_same_as_indexing() {
+    unsafe {
+        assert_eq!(*VALUES.get_dc(2), 3);
+        assert_eq!(VALUES.get_dc(1..3), &VALUES[1..3]);
+        assert_eq!(VALUES.get_dc(1..=2), &VALUES[1..=2]);
+        assert_eq!(VALUES.get_dc(..3), &VALUES[..3]);
+        assert_eq!(VALUES.get_dc(..=2), &VALUES[..=2]);
+        assert_eq!(VALUES.get_dc(3..), &VALUES[3..]);
+        assert_eq!(VALUES.get_dc(..), VALUES);
+    }
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 5")]
+    fn usize() {
+        unsafe { VALUES.get_dc(5) };
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 5 out of range for slice of length 3")]
+    fn range() {
+        unsafe { VALUES.get_dc(1..5) };
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 2 but ends at 1")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn range_with_start_after_end() {
+        unsafe { VALUES.get_dc(2..1) };
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 3 out of range for slice of length 3")]
+    fn range_inclusive() {
+        unsafe { VALUES.get_dc(1..=3) };
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 4 out of range for slice of length 3")]
+    fn range_to() {
+        unsafe { VALUES.get_dc(..4) };
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 3 out of range for slice of length 3")]
+    fn range_to_inclusive() {
+        unsafe { VALUES.get_dc(..=3) };
+    }
+
+    #[test]
+    #[should_panic(expected = "range start index 4 out of range for slice of length 3")]
+    fn range_from() {
+        unsafe { VALUES.get_dc(4..) };
+    }
+
+    #[test]
+    #[should_panic(expected = "range start index 4 out of range for slice of length 3")]
+    fn range_from_mut() {
+        let mut values = [1, 2, 3];
+        unsafe { values[..].get_dc_mut(4..) };
+    }
+}
@@ -0,0 +1,45 @@
+//! `DcSliceElements::split_at_raw_dc` returns halves which can alias through raw pointers.
+//!
+//! Run under Miri to check the accesses against Stacked Borrows: `cargo +nightly miri test --test split_at_raw`.
+
+use enderlook_debug_checked::DcSliceElements;
+
+#[test]
+fn halves_have_the_expected_lengths() {
+    let mut values = [0_u32; 6];
+    for mid in 0..=values.len() {
+        let (left, right) = unsafe { values.split_at_raw_dc(mid) };
+        assert_eq!((left.len(), right.len()), (mid, values.len() - mid));
+    }
+}
+
+#[test]
+fn halves_can_alias_through_raw_pointers() {
+    let mut values = [0_u32; 6];
+    let (left, right) = unsafe { values.split_at_raw_dc(2) };
+    let (left, right) = (left.cast::<u32>(), right.cast::<u32>());
+    // Both halves are derived from the same pointer without creating references,
+    // so using one of them doesn't invalidate the other, and each of them can reach the whole slice.
+    unsafe {
+        *left.add(3) = 1;
+        *right = 2;
+        *right.sub(1) = 3;
+        *left.add(5) += *right.add(1);
+        *left = *right.add(3);
+    }
+    assert_eq!(values, [1, 3, 2, 1, 0, 1]);
+}
+
+#[test]
+fn halves_can_be_reborrowed_while_the_other_is_used() {
+    let mut values = [1_u32, 2, 3, 4];
+    let (left, right) = unsafe { values.split_at_raw_dc(1) };
+    unsafe {
+        let left = &mut *left;
+        (*right)[0] += 10;
+        left[0] += (*right)[2];
+        (*right).reverse();
+        left[0] *= 2;
+    }
+    assert_eq!(values, [10, 4, 3, 12]);
+}
@@ -0,0 +1,49 @@
+//! `DcStr` rejects bounds that are not on char boundaries on Debug, with the same message as indexing the string.
+
+use enderlook_debug_checked::DcStr;
+
+const TEXT: &str = "a😀b";
+
+#[test]
+fn bounds_on_char_boundaries() {
+    unsafe {
+        assert_eq!(TEXT.get_dc(1..5), "😀");
+        assert_eq!(TEXT.get_dc(..1), "a");
+        assert_eq!(TEXT.get_dc(5..), "b");
+        assert_eq!(TEXT.get_expect_dc(1..=4, "message"), "😀");
+    }
+
+    let mut text = String::from(TEXT);
+    unsafe { text.get_dc_mut(5..).make_ascii_uppercase() };
+    assert_eq!(text, "a😀B");
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "byte index 2 is not a char boundary; it is inside '😀'")]
+    fn end_inside_an_emoji() {
+        unsafe { TEXT.get_dc(0..2) };
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 3 is not a char boundary; it is inside '😀'")]
+    fn start_inside_an_emoji() {
+        unsafe { TEXT.get_dc(3..) };
+    }
+
+    #[test]
+    #[should_panic(expected = "byte index 4 is not a char boundary; it is inside '😀'")]
+    fn mutable_slice_inside_an_emoji() {
+        let mut text = String::from(TEXT);
+        unsafe { text.get_dc_mut(..4) };
+    }
+
+    #[test]
+    #[should_panic(expected = "message")]
+    fn expect_inside_an_emoji() {
+        unsafe { TEXT.get_expect_dc(2..5, "message") };
+    }
+}
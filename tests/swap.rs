@@ -0,0 +1,73 @@
+//! `DcRefCell::swap` and `DcRefCell::swap_expect` state which cell is already borrowed on Debug.
+
+use enderlook_debug_checked::DcRefCell;
+
+#[test]
+fn swap_exchanges_the_values() {
+    let (a, b) = (DcRefCell::new(1), DcRefCell::new(2));
+    unsafe {
+        a.swap(&b);
+        assert_eq!((*a.borrow(), *b.borrow()), (2, 1));
+        a.swap_expect(&b, "swap");
+        assert_eq!((*a.borrow(), *b.borrow()), (1, 2));
+    }
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "cannot swap: the first (`self`) `DcRefCell` is already borrowed")]
+    fn swap_with_the_first_cell_borrowed() {
+        let (a, b) = (DcRefCell::new(1), DcRefCell::new(2));
+        unsafe {
+            let _guard = a.borrow();
+            a.swap(&b);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot swap: the second (`other`) `DcRefCell` is already borrowed")]
+    fn swap_with_the_second_cell_borrowed() {
+        let (a, b) = (DcRefCell::new(1), DcRefCell::new(2));
+        unsafe {
+            let _guard = b.borrow_mut();
+            a.swap(&b);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot swap a `DcRefCell` with itself")]
+    fn swap_with_itself() {
+        let a = DcRefCell::new(1);
+        unsafe { a.swap(&a) };
+    }
+
+    #[test]
+    #[should_panic(expected = "message: the first (`self`) `DcRefCell` is already borrowed")]
+    fn swap_expect_with_the_first_cell_borrowed() {
+        let (a, b) = (DcRefCell::new(1), DcRefCell::new(2));
+        unsafe {
+            let _guard = a.borrow_mut();
+            a.swap_expect(&b, "message");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "message: the second (`other`) `DcRefCell` is already borrowed")]
+    fn swap_expect_with_the_second_cell_borrowed() {
+        let (a, b) = (DcRefCell::new(1), DcRefCell::new(2));
+        unsafe {
+            let _guard = b.borrow();
+            a.swap_expect(&b, "message");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "message: cannot swap a `DcRefCell` with itself")]
+    fn swap_expect_with_itself() {
+        let a = DcRefCell::new(1);
+        unsafe { a.swap_expect(&a, "message") };
+    }
+}
@@ -0,0 +1,96 @@
+//! Panics of the Debug implementations are reported at the call site in the user code instead of inside the crate.
+#![cfg(debug_assertions)]
+
+use std::{
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use enderlook_debug_checked::*;
+
+thread_local! {
+    static LOCATION: RefCell<Option<(String, u32)>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, which must panic, and returns the file and line reported by the panic.
+fn panic_location(f: impl FnOnce()) -> (String, u32) {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        panic::set_hook(Box::new(|info| {
+            let location = info.location().unwrap();
+            LOCATION.with(|cell| *cell.borrow_mut() = Some((location.file().to_string(), location.line())));
+        }))
+    });
+    assert!(panic::catch_unwind(AssertUnwindSafe(f)).is_err(), "the expression didn't panic");
+    LOCATION.with(|cell| cell.borrow_mut().take()).unwrap()
+}
+
+/// Asserts that `$e` panics, reporting the line where this macro is invoked.
+macro_rules! assert_panics_here {
+    ($e:expr) => {{
+        let (file, line) = panic_location(|| {
+            #[allow(unused_unsafe)]
+            let _ = unsafe { $e };
+        });
+        assert_eq!((file.as_str(), line), (file!(), line!()), "`{}` reported the panic somewhere else", stringify!($e));
+    }};
+}
+
+#[test]
+fn dc_option() {
+    assert_panics_here!(None::<i32>.unwrap_dc());
+    assert_panics_here!(None::<i32>.expect_dc("msg"));
+    assert_panics_here!(None::<i32>.unwrap_dc_ref());
+    assert_panics_here!(None::<i32>.unwrap_dc_mut());
+    assert_panics_here!(None::<i32>.map_dc(|value| value + 1));
+    assert_panics_here!(None::<i32>.with_context_dc(|| "context"));
+    assert_panics_here!(None::<Box<i32>>.as_deref_dc());
+    assert_panics_here!(None::<i32>.unwrap_dc_or(0));
+    assert_panics_here!(Some(None::<i32>).unwrap_dc_flatten());
+}
+
+#[test]
+fn dc_result() {
+    assert_panics_here!(Err::<i32, i32>(0).unwrap_dc_ok());
+    assert_panics_here!(Err::<i32, i32>(0).expect_dc_ok("msg"));
+    assert_panics_here!(Err::<i32, i32>(0).unwrap_dc_ok_ref());
+    assert_panics_here!(Err::<i32, i32>(0).unwrap_dc_ok_mut());
+    assert_panics_here!(Err::<i32, i32>(0).with_context_dc_ok(|_| "context"));
+    assert_panics_here!(Err::<i32, i32>(0).unwrap_dc_ok_or(0));
+    assert_panics_here!(ok_dc!(Err::<i32, i32>(0)));
+    assert_panics_here!(Ok::<i32, i32>(0).unwrap_dc_err());
+    assert_panics_here!(Ok::<i32, i32>(0).expect_dc_err("msg"));
+    assert_panics_here!(Ok::<i32, i32>(0).unwrap_dc_err_ref());
+}
+
+#[test]
+fn dc_slice() {
+    let mut values = [1, 2, 3];
+    assert_panics_here!(values[..].get_dc(5));
+    assert_panics_here!(values[..].get_dc(1..5));
+    assert_panics_here!(values[..].get_expect_dc(5, "msg"));
+    assert_panics_here!(values[..].get_dc_mut(5));
+    assert_panics_here!(values[..].get_dc_or(5, &0));
+    assert_panics_here!(values.as_array_dc::<2>());
+    assert_panics_here!(values.rotate_left_dc(5));
+    assert_panics_here!("abc".get_dc(1..5));
+}
+
+#[test]
+fn dc_ref_cell() {
+    let cell = DcRefCell::new(0);
+    let _guard = unsafe { cell.borrow_mut() };
+    assert_panics_here!(cell.borrow());
+    assert_panics_here!(cell.borrow_mut());
+    assert_panics_here!(cell.replace(1));
+}
+
+#[cfg(feature = "verbose_names")]
+#[test]
+fn verbose_names() {
+    assert_panics_here!(None::<i32>.unwrap_checked_in_debug());
+    assert_panics_here!(Err::<i32, i32>(0).unwrap_ok_checked_in_debug());
+    assert_panics_here!(Ok::<i32, i32>(0).unwrap_err_checked_in_debug());
+    assert_panics_here!([1, 2, 3][..].get_checked_in_debug(5));
+}
@@ -0,0 +1,35 @@
+//! `DcRefCell::with_default` creates a cell with the default value of `T`, also in const contexts.
+#![feature(const_trait_impl)]
+
+use enderlook_debug_checked::DcRefCell;
+
+thread_local! {
+    static BUFFER: DcRefCell<Vec<u8>> = const { DcRefCell::with_default() };
+}
+
+#[test]
+fn in_const_contexts() {
+    let number: DcRefCell<i32> = const { DcRefCell::with_default() };
+    assert_eq!(number.into_inner(), 0);
+
+    BUFFER.with(|buffer| unsafe {
+        buffer.borrow_mut().push(1);
+        assert_eq!(*buffer.borrow(), [1]);
+    });
+}
+
+/// A type whose `Default` isn't const, so `with_default` can only be called at runtime.
+#[derive(Debug, PartialEq)]
+struct Runtime(String);
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Runtime(String::from("default"))
+    }
+}
+
+#[test]
+fn at_runtime_with_a_non_const_default() {
+    let cell: DcRefCell<Runtime> = DcRefCell::with_default();
+    assert_eq!(cell.into_inner(), Runtime(String::from("default")));
+}
@@ -0,0 +1,59 @@
+//! `DcSlice` on slices of zero-sized elements, whose length can reach `usize::MAX`.
+
+use std::ptr::NonNull;
+
+use enderlook_debug_checked::DcSlice;
+
+/// Returns a slice of `usize::MAX` unit values.
+fn units() -> &'static [()] {
+    // Safety: a dangling, aligned pointer is valid for any number of zero-sized values.
+    unsafe { std::slice::from_raw_parts(NonNull::dangling().as_ptr(), usize::MAX) }
+}
+
+/// Returns a mutable slice of `usize::MAX` unit values.
+fn units_mut() -> &'static mut [()] {
+    // Safety: a dangling, aligned pointer is valid for any number of zero-sized values.
+    unsafe { std::slice::from_raw_parts_mut(NonNull::dangling().as_ptr(), usize::MAX) }
+}
+
+#[test]
+fn get_dc_at_the_bounds() {
+    let slice = units();
+    unsafe {
+        assert_eq!(*slice.get_dc(0), ());
+        assert_eq!(*slice.get_dc(usize::MAX - 1), ());
+        assert_eq!(*units_mut().get_dc_mut(usize::MAX - 1), ());
+        assert_eq!(slice.get_dc(..).len(), usize::MAX);
+        assert_eq!(slice.get_dc(1..).len(), usize::MAX - 1);
+        assert_eq!(slice.get_dc(usize::MAX - 1..).len(), 1);
+        assert_eq!(slice.get_dc(usize::MAX..).len(), 0);
+        assert_eq!(slice.get_dc(..=usize::MAX - 1).len(), usize::MAX);
+        assert_eq!(slice.get_dc(0..usize::MAX).len(), usize::MAX);
+    }
+}
+
+#[test]
+fn contains_index_dc_at_the_bounds() {
+    let slice = units();
+    assert!(slice.contains_index_dc(usize::MAX - 1));
+    assert!(slice.contains_index_dc(..usize::MAX));
+    assert!(!slice.contains_index_dc(usize::MAX));
+    assert!(!slice.contains_index_dc(..=usize::MAX));
+}
+
+#[cfg(debug_assertions)]
+mod debug {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 18446744073709551615 but the index is 18446744073709551615")]
+    fn index_past_the_end() {
+        unsafe { units().get_dc(usize::MAX) };
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 18446744073709551615 out of range for slice of length 18446744073709551615")]
+    fn inclusive_range_up_to_usize_max() {
+        unsafe { units().get_dc(..=usize::MAX) };
+    }
+}